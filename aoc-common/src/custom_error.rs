@@ -0,0 +1,79 @@
+use miette::{Diagnostic, SourceSpan};
+use thiserror::Error;
+
+#[derive(Error, Diagnostic, Debug)]
+pub enum AocError {
+    #[error(transparent)]
+    #[diagnostic(code(aoc::io_error))]
+    IoError(#[from] std::io::Error),
+
+    #[error("Failed to parse input")]
+    #[diagnostic(code(aoc::parse_error))]
+    ParseError {
+        #[source_code]
+        input: String,
+        #[label("{reason}")]
+        span: SourceSpan,
+        reason: String,
+    },
+
+    #[error("No solution: {0}")]
+    #[diagnostic(code(aoc::no_solution))]
+    NoSolution(String),
+}
+
+impl AocError {
+    /// Parse error pointing at the start of `at`, which should be a slice of `input`
+    pub fn parse_error(input: &str, at: &str, reason: impl Into<String>) -> Self {
+        let offset = offset_in(input, at);
+        let length = input[offset..].chars().next().map_or(0, char::len_utf8);
+
+        Self::ParseError {
+            input: input.to_owned(),
+            span: (offset, length).into(),
+            reason: reason.into(),
+        }
+    }
+}
+
+// Byte offset of `at` inside of `input`
+// Falls back to treating `at` as a suffix of `input` when it is not a slice of it
+fn offset_in(input: &str, at: &str) -> usize {
+    let start = input.as_ptr() as usize;
+    let position = at.as_ptr() as usize;
+    if (start..=start + input.len()).contains(&position) {
+        position - start
+    } else {
+        input.len().saturating_sub(at.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_span() {
+        let input = "abc\ndef";
+        match AocError::parse_error(input, &input[5..], "unexpected `e`") {
+            AocError::ParseError { span, reason, .. } => {
+                assert_eq!(5, span.offset());
+                assert_eq!(1, span.len());
+                assert_eq!("unexpected `e`", reason);
+            }
+            other => panic!("Unexpected error: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_error_at_end_of_input() {
+        let input = "abc";
+        match AocError::parse_error(input, "", "unexpected end of input") {
+            AocError::ParseError { span, .. } => {
+                assert_eq!(3, span.offset());
+                assert_eq!(0, span.len());
+            }
+            other => panic!("Unexpected error: {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,62 @@
+//! Extrapolation of polynomial sequences from their successive differences
+
+/// Values following a sequence, as an infinite iterator
+///
+/// The sequence is differentiated until the differences are constant, then each difference is added
+/// back up (day 9's sensor readings). Exact whenever the sequence is a polynomial of degree lower
+/// than its number of values.
+#[derive(Debug, Clone)]
+pub struct Extrapolation {
+    // Last value of the sequence and of each of its differences
+    last: Vec<i64>,
+}
+
+impl Extrapolation {
+    pub fn new(values: &[i64]) -> Self {
+        let mut last = vec![];
+        let mut current = values.to_vec();
+        while let Some(&value) = current.last() {
+            last.push(value);
+            if current.iter().all(|&v| v == value) {
+                break;
+            }
+            current = current.windows(2).map(|w| w[1] - w[0]).collect();
+        }
+        Self { last }
+    }
+}
+
+impl Iterator for Extrapolation {
+    type Item = i64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for k in (1..self.last.len()).rev() {
+            self.last[k - 1] += self.last[k];
+        }
+        self.last.first().copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(vec![0, 3, 6, 9, 12, 15], vec![18, 21])]
+    #[case(vec![1, 3, 6, 10, 15, 21], vec![28, 36])]
+    #[case(vec![10, 13, 16, 21, 30, 45], vec![68, 101])]
+    #[case(vec![7], vec![7, 7])]
+    #[case(vec![], vec![])]
+    fn test_extrapolation(#[case] values: Vec<i64>, #[case] expected: Vec<i64>) {
+        let extrapolated: Vec<_> = Extrapolation::new(&values).take(2).collect();
+        assert_eq!(expected, extrapolated);
+    }
+
+    #[test]
+    fn test_far_extrapolation() {
+        // n² + 1
+        let mut extrapolation = Extrapolation::new(&[1, 2, 5]);
+        assert_eq!(Some(1_000_001), extrapolation.nth(1000 - 3));
+    }
+}
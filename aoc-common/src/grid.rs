@@ -0,0 +1,178 @@
+use ndarray::{Array2, ShapeError};
+use std::ops::{Deref, DerefMut};
+use thiserror::Error;
+
+/// Cardinal directions on a grid, with North pointing to the first row
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::West,
+        Direction::South,
+        Direction::East,
+    ];
+
+    pub fn opposite(&self) -> Self {
+        match self {
+            Direction::North => Direction::South,
+            Direction::South => Direction::North,
+            Direction::East => Direction::West,
+            Direction::West => Direction::East,
+        }
+    }
+
+    pub fn turn_left(&self) -> Self {
+        match self {
+            Direction::North => Direction::West,
+            Direction::West => Direction::South,
+            Direction::South => Direction::East,
+            Direction::East => Direction::North,
+        }
+    }
+
+    pub fn turn_right(&self) -> Self {
+        self.turn_left().opposite()
+    }
+
+    /// Index reached by moving one tile in this direction, only checked against the lower bounds
+    pub fn step(&self, [y, x]: [usize; 2]) -> Option<[usize; 2]> {
+        match self {
+            Direction::North => y.checked_sub(1).map(|y| [y, x]),
+            Direction::West => x.checked_sub(1).map(|x| [y, x]),
+            Direction::South => Some([y + 1, x]),
+            Direction::East => Some([y, x + 1]),
+        }
+    }
+}
+
+/// Error for characters that do not correspond to any kind of grid cell
+#[derive(Debug, Error)]
+#[error("unrecognized grid character `{0}`")]
+pub struct UnrecognizedChar(pub char);
+
+/// 2D grid indexed by `[row, column]`
+///
+/// Thin wrapper around [`Array2`] adding neighbor iteration, every other method is available through `Deref`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Array2<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(cells: Array2<T>) -> Self {
+        Self { cells }
+    }
+
+    /// Build a grid from a list of rows, which must all have the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, ShapeError> {
+        let nrows = rows.len();
+        let ncols = rows.first().map_or(0, |row| row.len());
+        let data = rows.into_iter().flatten().collect();
+        Array2::from_shape_vec([nrows, ncols], data).map(Self::new)
+    }
+
+    pub fn into_inner(self) -> Array2<T> {
+        self.cells
+    }
+
+    /// Whether a given index lies inside the grid
+    pub fn contains(&self, &[y, x]: &[usize; 2]) -> bool {
+        y < self.nrows() && x < self.ncols()
+    }
+
+    /// Index reached by moving one tile in some direction, if still inside the grid
+    pub fn step(&self, idx: [usize; 2], direction: Direction) -> Option<[usize; 2]> {
+        direction.step(idx).filter(|next| self.contains(next))
+    }
+
+    /// Index of the first cell (in row-major order) satisfying the predicate
+    pub fn position<P>(&self, mut predicate: P) -> Option<[usize; 2]>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.cells
+            .indexed_iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|((y, x), _)| [y, x])
+    }
+
+    /// 4-connected neighbors of a cell along with the direction they lie in
+    pub fn neighbors4(
+        &self,
+        idx: [usize; 2],
+    ) -> impl Iterator<Item = (Direction, [usize; 2])> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(idx, direction).map(|next| (direction, next)))
+    }
+
+    /// 8-connected neighbors of a cell
+    pub fn neighbors8(&self, [y, x]: [usize; 2]) -> impl Iterator<Item = [usize; 2]> + '_ {
+        (-1isize..=1)
+            .flat_map(|dy| (-1isize..=1).map(move |dx| (dy, dx)))
+            .filter(|&offset| offset != (0, 0))
+            .filter_map(move |(dy, dx)| {
+                let ny = y.checked_add_signed(dy)?;
+                let nx = x.checked_add_signed(dx)?;
+                Some([ny, nx])
+            })
+            .filter(|idx| self.contains(idx))
+    }
+}
+
+impl<T> From<Array2<T>> for Grid<T> {
+    fn from(cells: Array2<T>) -> Self {
+        Self::new(cells)
+    }
+}
+
+impl<T> Deref for Grid<T> {
+    type Target = Array2<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.cells
+    }
+}
+
+impl<T> DerefMut for Grid<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.cells
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn grid() -> Grid<u32> {
+        Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap()
+    }
+
+    #[test]
+    fn test_from_rows() {
+        assert!(Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5]]).is_err());
+        assert_eq!(6, grid()[[1, 2]]);
+    }
+
+    #[rstest]
+    #[case([0, 0], vec![(Direction::South, [1, 0]), (Direction::East, [0, 1])])]
+    #[case([1, 1], vec![(Direction::North, [0, 1]), (Direction::West, [1, 0]), (Direction::East, [1, 2])])]
+    fn test_neighbors4(#[case] idx: [usize; 2], #[case] expected: Vec<(Direction, [usize; 2])>) {
+        assert_eq!(expected, grid().neighbors4(idx).collect::<Vec<_>>());
+    }
+
+    #[rstest]
+    #[case([0, 0], vec![[0, 1], [1, 0], [1, 1]])]
+    #[case([1, 1], vec![[0, 0], [0, 1], [0, 2], [1, 0], [1, 2]])]
+    fn test_neighbors8(#[case] idx: [usize; 2], #[case] expected: Vec<[usize; 2]>) {
+        assert_eq!(expected, grid().neighbors8(idx).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,6 @@
+pub mod custom_error;
+pub mod extrapolation;
+pub mod grid;
+pub mod parsing;
+pub mod polygon;
+pub mod solution;
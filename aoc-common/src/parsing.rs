@@ -0,0 +1,164 @@
+use crate::{custom_error::AocError, grid::Grid};
+use nom::{
+    branch::alt,
+    character::complete::{line_ending, none_of},
+    combinator::{cut, eof, map_res, not, peek},
+    error::{ErrorKind, ParseError},
+    multi::{many1, separated_list1},
+    sequence::terminated,
+    Finish, IResult, Parser,
+};
+
+/// Run a parser over the whole input, turning any failure into an [`AocError::ParseError`]
+///
+/// Trailing whitespace is allowed, any other leftover input is reported as an error.
+pub fn parse_complete<'a, O, F>(input: &'a str, parser: F) -> Result<O, AocError>
+where
+    F: Parser<&'a str, O, nom::error::Error<&'a str>>,
+{
+    parse_fragment(input, input, parser)
+}
+
+/// Same as [`parse_complete`] but for a fragment of a bigger input (e.g. a single line),
+/// so that errors still point at their position inside of the whole input
+pub fn parse_fragment<'a, O, F>(
+    input: &str,
+    fragment: &'a str,
+    mut parser: F,
+) -> Result<O, AocError>
+where
+    F: Parser<&'a str, O, nom::error::Error<&'a str>>,
+{
+    let (rest, output) = parser
+        .parse(fragment)
+        .finish()
+        .map_err(|err| AocError::parse_error(input, err.input, describe(err.code)))?;
+
+    let rest = rest.trim_start();
+    if rest.is_empty() {
+        Ok(output)
+    } else {
+        Err(AocError::parse_error(input, rest, "unexpected input"))
+    }
+}
+
+// Human readable reason for a nom error
+fn describe(kind: ErrorKind) -> String {
+    match kind {
+        ErrorKind::MapRes | ErrorKind::MapOpt | ErrorKind::Verify => "invalid value".to_owned(),
+        ErrorKind::Eof => "expected end of input".to_owned(),
+        kind => format!("expected {}", kind.description().to_lowercase()),
+    }
+}
+
+/// One or more items separated by line endings
+///
+/// Parsing stops at the first empty line, while a non-empty line that the item parser rejects is a failure.
+pub fn lines<'a, O, E, F>(parser: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>, E>
+where
+    F: Parser<&'a str, O, E>,
+    E: ParseError<&'a str>,
+{
+    let separator = terminated(line_ending, not(alt((line_ending, eof))));
+    separated_list1(separator, cut(parser))
+}
+
+/// Lines of characters converted cell by cell into a [`Grid`]
+///
+/// Parsing stops at the first empty line, while an unrecognized character in the middle of a row is a failure.
+pub fn grid<T>(input: &str) -> IResult<&str, Grid<T>>
+where
+    T: TryFrom<char>,
+{
+    let cell = map_res(none_of("\r\n"), T::try_from);
+    let row = terminated(many1(cell), cut(peek(alt((line_ending, eof)))));
+    map_res(lines(row), Grid::from_rows)(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::UnrecognizedChar;
+    use nom::{bytes::complete::tag, character::complete};
+    use rstest::rstest;
+
+    #[derive(Debug, PartialEq)]
+    enum Cell {
+        Empty,
+        Wall,
+    }
+
+    impl TryFrom<char> for Cell {
+        type Error = UnrecognizedChar;
+
+        fn try_from(c: char) -> Result<Self, Self::Error> {
+            match c {
+                '.' => Ok(Self::Empty),
+                '#' => Ok(Self::Wall),
+                _ => Err(UnrecognizedChar(c)),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_complete() -> miette::Result<()> {
+        assert_eq!(
+            vec![1, 2, 3],
+            parse_complete("1\n2\n3\n", lines(complete::u32))?
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("1\n2\nx", 4)]
+    #[case("1\n2 3\n4", 4)]
+    #[case("1\n2\n99999999999", 4)]
+    fn test_parse_complete_error_span(#[case] input: &str, #[case] offset: usize) {
+        match parse_complete(input, lines(complete::u32)) {
+            Err(AocError::ParseError { span, .. }) => assert_eq!(offset, span.offset()),
+            other => panic!("Unexpected result: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_fragment_error_span() {
+        let input = "1 2\n3 x";
+        let line = input.lines().nth(1).unwrap();
+        match parse_fragment(input, line, separated_list1(tag(" "), complete::u32)) {
+            Err(AocError::ParseError { span, .. }) => assert_eq!(6, span.offset()),
+            other => panic!("Unexpected result: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_lines() {
+        let (rest, numbers) =
+            lines(complete::u32::<_, nom::error::Error<_>>)("1\n2\r\n3\n\n4").unwrap();
+        assert_eq!(vec![1, 2, 3], numbers);
+        assert_eq!("\n\n4", rest);
+    }
+
+    #[test]
+    fn test_lines_fails_at_invalid_line() {
+        match lines(complete::u32::<_, nom::error::Error<_>>)("1\nx\n3") {
+            Err(nom::Err::Failure(err)) => assert_eq!("x\n3", err.input),
+            other => panic!("Unexpected result: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_grid() {
+        let (rest, grid) = grid::<Cell>(".#.\n#..\n\n##").unwrap();
+        assert_eq!([2, 3], grid.shape());
+        assert_eq!(Cell::Wall, grid[[1, 0]]);
+        assert_eq!("\n\n##", rest);
+    }
+
+    #[test]
+    fn test_grid_fails_at_unrecognized_char() {
+        match grid::<Cell>(".#.\n#x.") {
+            Err(nom::Err::Failure(err)) => assert_eq!("x.", err.input),
+            other => panic!("Unexpected result: {other:?}"),
+        }
+    }
+}
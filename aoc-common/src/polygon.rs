@@ -0,0 +1,48 @@
+//! Area of polygons drawn on a grid, whose vertices have integer coordinates
+
+/// Twice the area of a polygon given its vertices in order (shoelace formula)
+///
+/// The area of a polygon with integer vertices can be a half-integer, while its double is always an integer.
+pub fn double_area(vertices: &[[i64; 2]]) -> i64 {
+    let sum: i64 = vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|([y1, x1], [y2, x2])| x1 * y2 - x2 * y1)
+        .sum();
+    sum.abs()
+}
+
+/// Number of integer points strictly inside a polygon with integer vertices (Pick's theorem)
+///
+/// `boundary_points` is the number of integer points lying on the edges of the polygon.
+pub fn interior_points(double_area: i64, boundary_points: i64) -> i64 {
+    // A = i + b/2 - 1
+    (double_area - boundary_points) / 2 + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    // Square of side 2
+    #[case(vec![[0, 0], [0, 2], [2, 2], [2, 0]], 8, 1)]
+    // Right triangle with legs of 3, winding the other way
+    #[case(vec![[0, 0], [3, 0], [0, 3]], 9, 1)]
+    fn test_polygon(
+        #[case] vertices: Vec<[i64; 2]>,
+        #[case] expected_double_area: i64,
+        #[case] expected_interior: i64,
+    ) {
+        let area = double_area(&vertices);
+        assert_eq!(expected_double_area, area);
+        // Both shapes above have an edge point every unit of length
+        let boundary: i64 = vertices
+            .iter()
+            .zip(vertices.iter().cycle().skip(1))
+            .map(|([y1, x1], [y2, x2])| (y2 - y1).abs().max((x2 - x1).abs()))
+            .sum();
+        assert_eq!(expected_interior, interior_points(area, boundary));
+    }
+}
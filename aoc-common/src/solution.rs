@@ -0,0 +1,172 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Answer to a puzzle part
+///
+/// Each day returns whatever numeric type fits its puzzle best (`u32`, `u64`, `usize`, `i32`, ...),
+/// so they are all widened into a single representation that can be compared and printed uniformly.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+macro_rules! impl_answer_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Number(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_number!(u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_owned())
+    }
+}
+
+/// Each puzzle is split in two parts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+#[error("unknown part `{0}`, expected `1` or `2`")]
+pub struct ParsePartError(String);
+
+impl FromStr for Part {
+    type Err = ParsePartError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            other => Err(ParsePartError(other.to_owned())),
+        }
+    }
+}
+
+/// Common interface implemented by the solution of every day
+pub trait Solution {
+    /// Day of the advent calendar
+    const DAY: u8;
+
+    /// Whether the solution can run in a browser, which has no threads (e.g. for rayon)
+    const WASM_COMPATIBLE: bool = true;
+
+    fn part1(input: &str) -> miette::Result<Answer>;
+
+    fn part2(input: &str) -> miette::Result<Answer>;
+
+    fn solve(part: Part, input: &str) -> miette::Result<Answer> {
+        match part {
+            Part::One => Self::part1(input),
+            Part::Two => Self::part2(input),
+        }
+    }
+}
+
+/// Type erased [`Solution`] so that solutions of different days can be stored together
+#[derive(Debug, Clone, Copy)]
+pub struct DynSolution {
+    pub day: u8,
+    pub wasm_compatible: bool,
+    part1: fn(&str) -> miette::Result<Answer>,
+    part2: fn(&str) -> miette::Result<Answer>,
+}
+
+impl DynSolution {
+    pub const fn of<S: Solution>() -> Self {
+        Self {
+            day: S::DAY,
+            wasm_compatible: S::WASM_COMPATIBLE,
+            part1: S::part1,
+            part2: S::part2,
+        }
+    }
+
+    pub fn solve(&self, part: Part, input: &str) -> miette::Result<Answer> {
+        match part {
+            Part::One => (self.part1)(input),
+            Part::Two => (self.part2)(input),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Answer::from(42u32), Answer::from(42usize))]
+    #[case(Answer::from(42u64), Answer::from(42i32))]
+    #[case(Answer::from(-7i32), Answer::Number(-7))]
+    fn test_answer_conversions(#[case] a: Answer, #[case] b: Answer) {
+        assert_eq!(a, b);
+    }
+
+    #[rstest]
+    #[case("1", Part::One)]
+    #[case("2", Part::Two)]
+    fn test_parse_part(#[case] s: &str, #[case] expected: Part) {
+        assert_eq!(expected, s.parse().unwrap());
+    }
+
+    #[test]
+    fn test_dyn_solution() -> miette::Result<()> {
+        struct Dummy;
+
+        impl Solution for Dummy {
+            const DAY: u8 = 42;
+
+            fn part1(input: &str) -> miette::Result<Answer> {
+                Ok(input.len().into())
+            }
+
+            fn part2(input: &str) -> miette::Result<Answer> {
+                Ok(input.into())
+            }
+        }
+
+        let solution = DynSolution::of::<Dummy>();
+        assert_eq!(42, solution.day);
+        assert!(solution.wasm_compatible);
+        assert_eq!(Answer::Number(3), solution.solve(Part::One, "abc")?);
+        assert_eq!(Answer::from("abc"), solution.solve(Part::Two, "abc")?);
+        Ok(())
+    }
+}
@@ -1,4 +1,18 @@
-pub mod custom_error;
+use aoc_common::solution::{Answer, Solution};
 
 pub mod part1;
 pub mod part2;
+
+pub struct Day{{ project-name | remove: "day-" }};
+
+impl Solution for Day{{ project-name | remove: "day-" }} {
+    const DAY: u8 = {{ project-name | remove: "day-" | plus: 0 }};
+
+    fn part1(input: &str) -> miette::Result<Answer> {
+        Ok(part1::process(input)?.into())
+    }
+
+    fn part2(input: &str) -> miette::Result<Answer> {
+        Ok(part2::process(input)?.into())
+    }
+}
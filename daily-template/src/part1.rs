@@ -1,4 +1,4 @@
-use crate::custom_error::AocError;
+use aoc_common::custom_error::AocError;
 
 #[tracing::instrument]
 pub fn process_line(line: &str) -> miette::Result<u32, AocError> {
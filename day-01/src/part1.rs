@@ -1,27 +1,30 @@
-use crate::custom_error::AocError;
+use aoc_common::custom_error::AocError;
 
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<u32, AocError> {
-    let output = input.lines().map(process_line).sum::<u32>();
+    let output = input
+        .lines()
+        .map(|line| {
+            process_line(line)
+                .ok_or_else(|| AocError::parse_error(input, line, "expected at least one digit"))
+        })
+        .sum::<Result<u32, _>>()?;
 
     Ok(output)
 }
 
-fn process_line(line: &str) -> u32 {
-    let (first, last) = get_fist_and_last_digits(line);
+fn process_line(line: &str) -> Option<u32> {
+    let (first, last) = get_fist_and_last_digits(line)?;
 
-    (first * 10) + last
+    Some((first * 10) + last)
 }
 
-fn get_fist_and_last_digits(line: &str) -> (u32, u32) {
+fn get_fist_and_last_digits(line: &str) -> Option<(u32, u32)> {
     // Transform line in an iterator over the decimal digits it contains
     let mut num_iter = line.chars().filter_map(|character| character.to_digit(10));
 
     // Retrieve the first digit in the line
-    // It is guaranteed to have at least one digit per line
-    let first = num_iter
-        .next()
-        .expect("There should be at least one number in each line!");
+    let first = num_iter.next()?;
 
     // Get the last digit in the line
     let last = match num_iter.last() {
@@ -30,7 +33,7 @@ fn get_fist_and_last_digits(line: &str) -> (u32, u32) {
         None => first,
     };
 
-    (first, last)
+    Some((first, last))
 }
 
 #[cfg(test)]
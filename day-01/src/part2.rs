@@ -1,15 +1,21 @@
-use crate::custom_error::AocError;
+use aoc_common::custom_error::AocError;
 
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<u32, AocError> {
-    let output: u32 = input.lines().map(process_line).sum();
+    let output = input
+        .lines()
+        .map(|line| {
+            process_line(line)
+                .ok_or_else(|| AocError::parse_error(input, line, "expected at least one digit"))
+        })
+        .sum::<Result<u32, _>>()?;
     Ok(output)
 }
 
-fn process_line(line: &str) -> u32 {
-    let (first, last) = get_fist_and_last_digits(line);
+fn process_line(line: &str) -> Option<u32> {
+    let (first, last) = get_fist_and_last_digits(line)?;
 
-    (first * 10) + last
+    Some((first * 10) + last)
 }
 
 const NUM_NAMES: [(&str, u32); 9] = [
@@ -24,7 +30,7 @@ const NUM_NAMES: [(&str, u32); 9] = [
     ("nine", 9),
 ];
 
-fn get_fist_and_last_digits(line: &str) -> (u32, u32) {
+fn get_fist_and_last_digits(line: &str) -> Option<(u32, u32)> {
     // Iterator over the digits found in the line
     let mut num_iter = line.char_indices().filter_map(|(index, char)| {
         if let Some((_, num)) = NUM_NAMES
@@ -38,10 +44,7 @@ fn get_fist_and_last_digits(line: &str) -> (u32, u32) {
     });
 
     // Retrieve the first digit in the line
-    // It is guaranteed to have at least one digit per line
-    let first = num_iter
-        .next()
-        .expect("There should be at least one number in each line!");
+    let first = num_iter.next()?;
 
     // Concatenate the first and last digit and parse it as a number
     // If the line contains only one digit, then use it twice
@@ -50,7 +53,7 @@ fn get_fist_and_last_digits(line: &str) -> (u32, u32) {
         None => first,
     };
 
-    (first, last)
+    Some((first, last))
 }
 
 #[cfg(test)]
@@ -67,7 +70,7 @@ mod tests {
     #[case("zoneight234", 14)]
     #[case("7pqrstsixteen", 76)]
     fn line_test(#[case] line: &str, #[case] expected: u32) {
-        assert_eq!(expected, process_line(line))
+        assert_eq!(Some(expected), process_line(line))
     }
 
     #[test]
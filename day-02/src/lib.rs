@@ -1,4 +1,18 @@
-pub mod custom_error;
+use aoc_common::solution::{Answer, Solution};
 
 pub mod part1;
 pub mod part2;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    fn part1(input: &str) -> miette::Result<Answer> {
+        Ok(part1::process(input)?.into())
+    }
+
+    fn part2(input: &str) -> miette::Result<Answer> {
+        Ok(part2::process(input)?.into())
+    }
+}
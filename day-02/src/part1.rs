@@ -1,7 +1,9 @@
-use crate::custom_error::AocError;
+use aoc_common::custom_error::AocError;
 use itertools::Itertools;
 use once_cell::sync::Lazy;
 use regex::Regex;
+use std::num::ParseIntError;
+use thiserror::Error;
 
 /// Set of colored cubes
 #[derive(Debug, PartialEq)]
@@ -25,26 +27,30 @@ pub fn process(input: &str) -> miette::Result<u32, AocError> {
         // Read input line by line
         .lines()
         // Parse game from each line
-        // TODO: Error propagation
-        .map(|line| parse_game(line).unwrap())
+        .map(|line| {
+            parse_game(line).map_err(|err| AocError::parse_error(input, line, err.to_string()))
+        })
         // Filter the games that could have been possible
         // if the Elf had 12 red, 13 green and 14 blue cubes
-        .filter(|game| {
+        .filter_ok(|game| {
             game.tirages
                 .iter()
                 .all(|h| h.red <= 12 && h.green <= 13 && h.blue <= 14)
         })
         // Get their ids
-        .map(|game| game.id)
+        .map_ok(|game| game.id)
         // Sum them
-        .sum();
+        .sum::<Result<_, _>>()?;
 
     Ok(result)
 }
 
-#[derive(Debug)]
+#[derive(Debug, Error)]
 enum ParseGameError {
+    #[error("expected `Game <id>: `")]
     IDNotFound,
+    #[error("invalid number of cubes or game id: {0}")]
+    InvalidNumber(#[from] ParseIntError),
 }
 
 fn parse_game(line: &str) -> Result<Game, ParseGameError> {
@@ -55,9 +61,7 @@ fn parse_game(line: &str) -> Result<Game, ParseGameError> {
     // Parse Game id
     let cap = RE_GAME.captures(line).ok_or(ParseGameError::IDNotFound)?;
 
-    let id = cap[1]
-        .parse::<u32>()
-        .expect("Once a capture is found, parsing to integer should never fail!");
+    let id = cap[1].parse::<u32>()?;
 
     // String index where the game can started to be parsed
     let first_index = cap
@@ -78,17 +82,15 @@ fn parse_game(line: &str) -> Result<Game, ParseGameError> {
                 .map(|m| first_index + m.end()),
         )
         .chain([line.len()])
-        .into_iter()
         .tuple_windows()
         .map(|(a, b)| &line[a..b])
-        // TODO: Return `Result` from cube set parser and propagate error to game parser
         .map(parse_cube_set)
-        .collect();
+        .collect::<Result<_, _>>()?;
 
     Ok(Game { id, tirages })
 }
 
-fn parse_cube_set(cube_set_str: &str) -> CubeSet {
+fn parse_cube_set(cube_set_str: &str) -> Result<CubeSet, ParseGameError> {
     // Lazily construct regexes
     static RE_COLORS: [Lazy<Regex>; 3] = [
         Lazy::new(|| Regex::new(r"(\d+) red").expect("This regex construction should never fail!")),
@@ -100,24 +102,17 @@ fn parse_cube_set(cube_set_str: &str) -> CubeSet {
         }),
     ];
 
-    let counts: Vec<u32> = RE_COLORS
-        .iter()
-        .map(|re| {
-            if let Some(cap) = re.captures(cube_set_str) {
-                cap[1]
-                    .parse::<u32>()
-                    .expect("Once a capture is found, parsing to integer should never fail!")
-            } else {
-                0
-            }
-        })
-        .collect();
-
-    CubeSet {
-        red: counts[0],
-        green: counts[1],
-        blue: counts[2],
-    }
+    // Colors missing from the set count as zero cubes
+    let count = |re: &Regex| match re.captures(cube_set_str) {
+        Some(cap) => cap[1].parse::<u32>(),
+        None => Ok(0),
+    };
+
+    Ok(CubeSet {
+        red: count(&RE_COLORS[0])?,
+        green: count(&RE_COLORS[1])?,
+        blue: count(&RE_COLORS[2])?,
+    })
 }
 
 #[cfg(test)]
@@ -139,6 +134,16 @@ mod tests {
         )
     }
 
+    #[rstest]
+    #[case("Game 99999999999: 3 blue, 4 red")]
+    #[case("Game 1: 3 blue, 99999999999 red")]
+    fn test_invalid_line(#[case] line: &str) {
+        assert!(matches!(
+            parse_game(line),
+            Err(ParseGameError::InvalidNumber(_))
+        ));
+    }
+
     #[test]
     fn test_process() -> miette::Result<()> {
         let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
@@ -1,7 +1,9 @@
-use crate::custom_error::AocError;
+use aoc_common::custom_error::AocError;
 use itertools::Itertools;
 use once_cell::sync::Lazy;
 use regex::Regex;
+use std::num::ParseIntError;
+use thiserror::Error;
 
 /// Set of colored cubes
 #[derive(Debug, Clone, PartialEq)]
@@ -36,13 +38,14 @@ struct Game {
 impl Game {
     // Fewest number of cubes of each color that are necessary for the game to be possible
     pub fn fewest_number_of_cubes(&self) -> CubeSet {
-        self.tirages
-            // TODO: Is this clone necessary
-            .clone()
-            .into_iter()
-            .reduce(|acc, h| acc.max(&h))
-            .unwrap()
-            .clone()
+        self.tirages.iter().fold(
+            CubeSet {
+                red: 0,
+                green: 0,
+                blue: 0,
+            },
+            |acc, h| acc.max(h),
+        )
     }
 }
 
@@ -52,19 +55,23 @@ pub fn process(input: &str) -> miette::Result<u32, AocError> {
         // Read input line by line
         .lines()
         // Parse game from each line
-        // TODO: Error propagation
-        .map(|line| parse_game(line).unwrap())
+        .map(|line| {
+            parse_game(line).map_err(|err| AocError::parse_error(input, line, err.to_string()))
+        })
         // Get the power of the fewest number of cubes necessary
-        .map(|game| game.fewest_number_of_cubes().power())
+        .map_ok(|game| game.fewest_number_of_cubes().power())
         // Sum them
-        .sum();
+        .sum::<Result<_, _>>()?;
 
     Ok(result)
 }
 
-#[derive(Debug)]
+#[derive(Debug, Error)]
 enum ParseGameError {
+    #[error("expected `Game <id>: `")]
     IDNotFound,
+    #[error("invalid number of cubes or game id: {0}")]
+    InvalidNumber(#[from] ParseIntError),
 }
 
 fn parse_game(line: &str) -> Result<Game, ParseGameError> {
@@ -75,9 +82,7 @@ fn parse_game(line: &str) -> Result<Game, ParseGameError> {
     // Parse Game id
     let cap = RE_GAME.captures(line).ok_or(ParseGameError::IDNotFound)?;
 
-    let id = cap[1]
-        .parse::<u32>()
-        .expect("Once a capture is found, parsing to integer should never fail!");
+    let id = cap[1].parse::<u32>()?;
 
     // String index where the game can started to be parsed
     let first_index = cap
@@ -98,17 +103,15 @@ fn parse_game(line: &str) -> Result<Game, ParseGameError> {
                 .map(|m| first_index + m.end()),
         )
         .chain([line.len()])
-        .into_iter()
         .tuple_windows()
         .map(|(a, b)| &line[a..b])
-        // TODO: Return `Result` from cube set parser and propagate error to game parser
         .map(parse_cube_set)
-        .collect();
+        .collect::<Result<_, _>>()?;
 
     Ok(Game { id, tirages })
 }
 
-fn parse_cube_set(cube_set_str: &str) -> CubeSet {
+fn parse_cube_set(cube_set_str: &str) -> Result<CubeSet, ParseGameError> {
     // Lazily construct regexes
     static RE_COLORS: [Lazy<Regex>; 3] = [
         Lazy::new(|| Regex::new(r"(\d+) red").expect("This regex construction should never fail!")),
@@ -120,24 +123,17 @@ fn parse_cube_set(cube_set_str: &str) -> CubeSet {
         }),
     ];
 
-    let counts: Vec<u32> = RE_COLORS
-        .iter()
-        .map(|re| {
-            if let Some(cap) = re.captures(cube_set_str) {
-                cap[1]
-                    .parse::<u32>()
-                    .expect("Once a capture is found, parsing to integer should never fail!")
-            } else {
-                0
-            }
-        })
-        .collect();
-
-    CubeSet {
-        red: counts[0],
-        green: counts[1],
-        blue: counts[2],
-    }
+    // Colors missing from the set count as zero cubes
+    let count = |re: &Regex| match re.captures(cube_set_str) {
+        Some(cap) => cap[1].parse::<u32>(),
+        None => Ok(0),
+    };
+
+    Ok(CubeSet {
+        red: count(&RE_COLORS[0])?,
+        green: count(&RE_COLORS[1])?,
+        blue: count(&RE_COLORS[2])?,
+    })
 }
 
 #[cfg(test)]
@@ -160,6 +156,16 @@ mod tests {
         )
     }
 
+    #[rstest]
+    #[case("Game 99999999999: 3 blue, 4 red")]
+    #[case("Game 1: 3 blue, 99999999999 red")]
+    fn test_invalid_line(#[case] line: &str) {
+        assert!(matches!(
+            parse_game(line),
+            Err(ParseGameError::InvalidNumber(_))
+        ));
+    }
+
     #[test]
     fn test_process() -> miette::Result<()> {
         let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
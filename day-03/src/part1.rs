@@ -1,7 +1,8 @@
-use crate::custom_error::AocError;
-use itertools::Itertools;
-use once_cell::sync::Lazy;
-use regex::Regex;
+use aoc_common::{
+    custom_error::AocError,
+    grid::Grid,
+    parsing::{grid, parse_complete},
+};
 use std::ops::Range;
 
 #[derive(Debug)]
@@ -11,90 +12,58 @@ struct NumberPosition {
     position: Range<usize>,
 }
 
-#[derive(Debug)]
-struct CharGrid<'a> {
-    ncols: usize,
-    lines: Vec<&'a str>,
+fn is_symbol(c: char) -> bool {
+    !c.is_ascii_digit() && c != '.'
 }
 
-impl<'a> CharGrid<'a> {
-    pub fn new(text: &'a str) -> Self {
-        let lines: Vec<&'a str> = text.lines().collect();
-        assert!(lines.iter().map(|line| line.len()).all_equal());
+#[derive(Debug)]
+struct Schematic {
+    grid: Grid<char>,
+}
 
-        Self {
-            ncols: lines[0].len(),
-            lines,
-        }
+impl Schematic {
+    pub fn new(text: &str) -> Result<Self, AocError> {
+        let grid = parse_complete(text, grid)?;
+        Ok(Self { grid })
     }
 
     pub fn find_all_numbers(&self) -> Vec<NumberPosition> {
-        static RE_NUM: Lazy<Regex> =
-            Lazy::new(|| Regex::new(r"\d+").expect("This regex creation should never fail!"));
-
-        self.lines
-            .iter()
-            .enumerate()
-            .flat_map(|(i, &line)| {
-                RE_NUM.find_iter(line).map(move |m| NumberPosition {
-                    number: m
-                        .as_str()
-                        .parse()
-                        .expect("Parsing this integer should never fail!"),
-                    line_num: i,
-                    position: m.range(),
-                })
-            })
-            .collect()
+        let mut numbers = Vec::new();
+        for (line_num, row) in self.grid.rows().into_iter().enumerate() {
+            let mut digits_start = None;
+            // Append a trailing non-digit so that numbers at the end of a line are also closed
+            for (idx, c) in row.iter().chain(&['.']).enumerate() {
+                match (c.is_ascii_digit(), digits_start) {
+                    (true, None) => digits_start = Some(idx),
+                    (false, Some(start)) => {
+                        numbers.push(NumberPosition {
+                            number: row
+                                .iter()
+                                .skip(start)
+                                .take(idx - start)
+                                .fold(0, |acc, d| 10 * acc + d.to_digit(10).unwrap()),
+                            line_num,
+                            position: start..idx,
+                        });
+                        digits_start = None;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        numbers
     }
 
     pub fn find_part_numbers(&self) -> Vec<u32> {
         self.find_all_numbers()
             .into_iter()
+            // A part number has a symbol among the neighbors of any of its digits
             .filter(|np| {
-                let own_line = self.lines[np.line_num];
-                // Neighboring chars in same line
-                let prev_idx = match np.position.start {
-                    0 => None,
-                    _ => Some(np.position.start - 1),
-                };
-                let next_idx = if np.position.end == self.ncols {
-                    None
-                } else {
-                    Some(np.position.end)
-                };
-
-                // Neighboring lines
-                let prev_line = match np.line_num {
-                    0 => None,
-                    _ => Some(self.lines[np.line_num - 1]),
-                };
-                let next_line = if np.line_num == self.lines.len() - 1 {
-                    None
-                } else {
-                    Some(self.lines[np.line_num + 1])
-                };
-
-                // Symbol regex
-                // TODO: Scan grid for symbols
-                static RE_SYMB: Lazy<Regex> = Lazy::new(|| {
-                    Regex::new(r"(?:\+|\*|\#|\$|\%|\&|\/|\-|\=|\@)")
-                        .expect("This regex creation should never fail!")
-                });
-
-                [prev_idx, next_idx]
-                    .into_iter()
-                    .flatten()
-                    .any(|idx| RE_SYMB.is_match(&own_line[idx..=idx]))
-                    || [prev_line, next_line].into_iter().flatten().any(|line| {
-                        let prev_char_idx = if let Some(idx) = prev_idx { idx } else { 0 };
-                        let next_char_idx = if let Some(idx) = next_idx {
-                            idx
-                        } else {
-                            self.ncols - 1
-                        };
-                        RE_SYMB.is_match(&line[prev_char_idx..=next_char_idx])
-                    })
+                np.position.clone().any(|col| {
+                    self.grid
+                        .neighbors8([np.line_num, col])
+                        .any(|idx| is_symbol(self.grid[idx]))
+                })
             })
             .map(|np| np.number)
             .collect()
@@ -103,9 +72,9 @@ impl<'a> CharGrid<'a> {
 
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<u32, AocError> {
-    let grid = CharGrid::new(input);
+    let schematic = Schematic::new(input)?;
     // Find part numbers
-    let part_numbers = grid.find_part_numbers();
+    let part_numbers = schematic.find_part_numbers();
 
     Ok(part_numbers.iter().sum())
 }
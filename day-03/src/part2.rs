@@ -1,15 +1,10 @@
-use crate::custom_error::AocError;
-use itertools::Itertools;
-use once_cell::sync::Lazy;
-use regex::Regex;
+use aoc_common::{
+    custom_error::AocError,
+    grid::Grid,
+    parsing::{grid, parse_complete},
+};
 use std::ops::Range;
 
-#[derive(Debug)]
-struct CharGrid<'a> {
-    ncols: usize,
-    lines: Vec<&'a str>,
-}
-
 #[derive(Debug)]
 struct GridPosition {
     line_num: usize,
@@ -17,12 +12,8 @@ struct GridPosition {
 }
 
 impl GridPosition {
-    pub fn is_adjacent_to(&self, other: &Self) -> bool {
-        match (self.line_num as isize) - (other.line_num as isize) {
-            // Same line
-            -1..=1 => other.range.start <= self.range.end && self.range.start <= other.range.end,
-            _ => false,
-        }
+    pub fn contains(&self, &[line_num, col]: &[usize; 2]) -> bool {
+        self.line_num == line_num && self.range.contains(&col)
     }
 }
 
@@ -32,111 +23,66 @@ struct NumberWithPosition {
     position: GridPosition,
 }
 
-impl<'a> CharGrid<'a> {
-    pub fn new(text: &'a str) -> Self {
-        let lines: Vec<&'a str> = text.lines().collect();
-        assert!(lines.iter().map(|line| line.len()).all_equal());
+#[derive(Debug)]
+struct Schematic {
+    grid: Grid<char>,
+}
 
-        Self {
-            ncols: lines[0].len(),
-            lines,
-        }
+impl Schematic {
+    pub fn new(text: &str) -> Result<Self, AocError> {
+        let grid = parse_complete(text, grid)?;
+        Ok(Self { grid })
     }
 
     pub fn find_all_numbers(&self) -> Vec<NumberWithPosition> {
-        static RE_NUM: Lazy<Regex> =
-            Lazy::new(|| Regex::new(r"\d+").expect("This regex creation should never fail!"));
-
-        self.lines
-            .iter()
-            .enumerate()
-            .flat_map(|(i, &line)| {
-                RE_NUM.find_iter(line).map(move |m| NumberWithPosition {
-                    number: m
-                        .as_str()
-                        .parse()
-                        .expect("Parsing this integer should never fail!"),
-                    position: GridPosition {
-                        line_num: i,
-                        range: m.range(),
-                    },
-                })
-            })
-            .collect()
+        let mut numbers = Vec::new();
+        for (line_num, row) in self.grid.rows().into_iter().enumerate() {
+            let mut digits_start = None;
+            // Append a trailing non-digit so that numbers at the end of a line are also closed
+            for (idx, c) in row.iter().chain(&['.']).enumerate() {
+                match (c.is_ascii_digit(), digits_start) {
+                    (true, None) => digits_start = Some(idx),
+                    (false, Some(start)) => {
+                        numbers.push(NumberWithPosition {
+                            number: row
+                                .iter()
+                                .skip(start)
+                                .take(idx - start)
+                                .fold(0, |acc, d| 10 * acc + d.to_digit(10).unwrap()),
+                            position: GridPosition {
+                                line_num,
+                                range: start..idx,
+                            },
+                        });
+                        digits_start = None;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        numbers
     }
 
-    pub fn find_part_numbers(&self) -> Vec<u32> {
-        self.find_all_numbers()
-            .into_iter()
-            .filter(|num_pos| {
-                let own_line = self.lines[num_pos.position.line_num];
-                // Neighboring chars in same line
-                let prev_idx = match num_pos.position.range.start {
-                    0 => None,
-                    _ => Some(num_pos.position.range.start - 1),
-                };
-                let next_idx = if num_pos.position.range.end == self.ncols {
-                    None
-                } else {
-                    Some(num_pos.position.range.end)
-                };
-
-                // Neighboring lines
-                let prev_line = match num_pos.position.line_num {
-                    0 => None,
-                    _ => Some(self.lines[num_pos.position.line_num - 1]),
-                };
-                let next_line = if num_pos.position.line_num == self.lines.len() - 1 {
-                    None
-                } else {
-                    Some(self.lines[num_pos.position.line_num + 1])
-                };
-
-                // Symbol regex
-                // TODO: Scan grid for symbols
-                static RE_SYMB: Lazy<Regex> = Lazy::new(|| {
-                    Regex::new(r"(?:\+|\*|\#|\$|\%|\&|\/|\-|\=|\@)")
-                        .expect("This regex creation should never fail!")
-                });
-
-                [prev_idx, next_idx]
-                    .into_iter()
-                    .flatten()
-                    .any(|idx| RE_SYMB.is_match(&own_line[idx..=idx]))
-                    || [prev_line, next_line].into_iter().flatten().any(|line| {
-                        let prev_char_idx = if let Some(idx) = prev_idx { idx } else { 0 };
-                        let next_char_idx = if let Some(idx) = next_idx {
-                            idx
-                        } else {
-                            self.ncols - 1
-                        };
-                        RE_SYMB.is_match(&line[prev_char_idx..=next_char_idx])
-                    })
-            })
-            .map(|np| np.number)
+    pub fn find_all_gears(&self) -> Vec<[usize; 2]> {
+        self.grid
+            .indexed_iter()
+            .filter(|(_, &c)| c == '*')
+            .map(|((y, x), _)| [y, x])
             .collect()
     }
 
-    pub fn find_all_gears(&self) -> Vec<GridPosition> {
-        static RE_GEAR: Lazy<Regex> =
-            Lazy::new(|| Regex::new(r"\*").expect("This regex creation should never fail!"));
-
-        self.lines
+    pub fn get_adjacent_numbers(
+        &self,
+        numbers: &[NumberWithPosition],
+        gear_idx: [usize; 2],
+    ) -> Vec<u32> {
+        numbers
             .iter()
-            .enumerate()
-            .flat_map(|(line_num, &line)| {
-                RE_GEAR.find_iter(line).map(move |m| GridPosition {
-                    line_num,
-                    range: m.range(),
-                })
+            .filter(|num_pos| {
+                self.grid
+                    .neighbors8(gear_idx)
+                    .any(|idx| num_pos.position.contains(&idx))
             })
-            .collect()
-    }
-
-    pub fn get_adjacent_numbers(&self, gear_pos: &GridPosition) -> Vec<u32> {
-        self.find_all_numbers()
-            .into_iter()
-            .filter(|num_pos| num_pos.position.is_adjacent_to(gear_pos))
             .map(|num_pos| num_pos.number)
             .collect()
     }
@@ -144,13 +90,13 @@ impl<'a> CharGrid<'a> {
 
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<u32, AocError> {
-    let grid = CharGrid::new(input);
-    // Find part numbers
-    let result = grid
+    let schematic = Schematic::new(input)?;
+    let numbers = schematic.find_all_numbers();
+    let result = schematic
         .find_all_gears()
         .into_iter()
-        // Get the numbers adjacents to each grid
-        .map(|gear_pos| grid.get_adjacent_numbers(&gear_pos))
+        // Get the numbers adjacents to each gear
+        .map(|gear_idx| schematic.get_adjacent_numbers(&numbers, gear_idx))
         // Consider only gears adjacent to exactly 2 numbers
         .filter(|numbers_list| numbers_list.len() == 2)
         // Calculate gear ratios by multiplying these numbers
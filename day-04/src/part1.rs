@@ -1,4 +1,7 @@
-use crate::custom_error::AocError;
+use aoc_common::{
+    custom_error::AocError,
+    parsing::{parse_complete, parse_fragment},
+};
 use nom::{
     bytes::complete::tag,
     character::complete::{self, space0, space1},
@@ -72,7 +75,7 @@ fn parse_card(input: &str) -> IResult<&str, ScratchCard> {
 
 #[tracing::instrument]
 pub fn process_line(line: &str) -> miette::Result<u32, AocError> {
-    let (_, card) = parse_card(line).unwrap();
+    let card = parse_complete(line, parse_card)?;
 
     Ok(card.score())
 }
@@ -81,8 +84,8 @@ pub fn process_line(line: &str) -> miette::Result<u32, AocError> {
 pub fn process(input: &str) -> miette::Result<u32, AocError> {
     let score_sum = input
         .lines()
-        .map(|line| process_line(line).expect("Line parsing should work!"))
-        .sum();
+        .map(|line| parse_fragment(input, line, parse_card).map(|card| card.score()))
+        .sum::<Result<_, _>>()?;
     Ok(score_sum)
 }
 
@@ -1,4 +1,4 @@
-use crate::custom_error::AocError;
+use aoc_common::{custom_error::AocError, parsing::parse_fragment};
 use nom::{
     bytes::complete::tag,
     character::complete::{self, space0, space1},
@@ -71,11 +71,8 @@ pub fn process(input: &str) -> miette::Result<usize, AocError> {
     // Parse all cards from input
     let cards: Vec<ScratchCard> = input
         .lines()
-        .map(|line| {
-            let (_, card) = parse_card(line).unwrap();
-            card
-        })
-        .collect();
+        .map(|line| parse_fragment(input, line, parse_card))
+        .collect::<Result<_, _>>()?;
 
     let cards_count: usize = cards
         .iter()
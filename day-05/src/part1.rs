@@ -1,7 +1,7 @@
-use crate::custom_error::AocError;
+use aoc_common::{custom_error::AocError, parsing::parse_complete};
 use nom::{
-    bytes::complete::{tag, take_until},
-    character::complete::{self, line_ending, space1},
+    bytes::complete::{is_not, tag},
+    character::complete::{self, line_ending, multispace1, space1},
     multi::{many1, separated_list1},
     sequence::tuple,
     IResult, Parser,
@@ -97,8 +97,10 @@ fn parse_range_mapping(input: &str) -> IResult<&str, RangeMapping> {
 }
 
 fn parse_map(input: &str) -> IResult<&str, Map> {
-    take_until("map:")
-        .precedes(tag("map:"))
+    // Header of the map, e.g. `seed-to-soil map:`
+    multispace1
+        .precedes(is_not(" \r\n"))
+        .precedes(tag(" map:"))
         .precedes(many1(line_ending.precedes(parse_range_mapping)))
         .parse(input)
         .map(|(input, mappings)| (input, Map { mappings }))
@@ -112,13 +114,13 @@ fn parse_almanac(input: &str) -> IResult<&str, Almanac> {
 
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<u64, AocError> {
-    let (_, almanac) = parse_almanac(input).unwrap();
+    let almanac = parse_complete(input, parse_almanac)?;
 
-    Ok(*almanac
+    almanac
         .lookup()
-        .iter()
+        .into_iter()
         .min()
-        .expect("List of final locations should not be empty!"))
+        .ok_or_else(|| AocError::NoSolution("there are no seeds to plant".to_owned()))
 }
 
 #[cfg(test)]
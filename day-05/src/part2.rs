@@ -1,12 +1,13 @@
-use crate::custom_error::AocError;
+use aoc_common::{custom_error::AocError, parsing::parse_complete};
 use nom::{
-    bytes::complete::{tag, take_until},
-    character::complete::{self, line_ending, space1},
+    bytes::complete::{is_not, tag},
+    character::complete::{self, line_ending, multispace1, space1},
     multi::{many1, separated_list1},
     sequence::{separated_pair, tuple},
     IResult, Parser,
 };
 use nom_supreme::ParserExt;
+#[cfg(feature = "brute-force")]
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use std::ops::Range;
 
@@ -45,6 +46,7 @@ struct Map {
 }
 
 impl Map {
+    #[cfg(feature = "brute-force")]
     fn lookup(&self, num: u64) -> u64 {
         if let Some(range_mapping) = self
             .mappings
@@ -56,6 +58,38 @@ impl Map {
             num
         }
     }
+
+    // Map a whole range of numbers at once, splitting it at the boundaries of the range mappings
+    fn lookup_range(&self, range: Range<u64>) -> Vec<Range<u64>> {
+        let mut mapped = Vec::new();
+        let mut unmapped = vec![range];
+
+        for range_mapping in &self.mappings {
+            let source_range = range_mapping.source_range();
+            let mut remaining = Vec::new();
+
+            for range in unmapped {
+                let start = range.start.max(source_range.start);
+                let end = range.end.min(source_range.end);
+                if start < end {
+                    mapped.push(range_mapping.map(start)..range_mapping.map(end));
+                    remaining.extend(
+                        [range.start..start, end..range.end]
+                            .into_iter()
+                            .filter(|piece| !piece.is_empty()),
+                    );
+                } else {
+                    remaining.push(range);
+                }
+            }
+
+            unmapped = remaining;
+        }
+
+        // Numbers not covered by any range mapping keep their value
+        mapped.extend(unmapped);
+        mapped
+    }
 }
 
 #[derive(Debug)]
@@ -65,12 +99,22 @@ struct Almanac {
 }
 
 impl Almanac {
+    #[cfg(feature = "brute-force")]
     fn lookup(&self) -> impl ParallelIterator<Item = u64> + '_ {
         self.seeds
             .par_iter()
             .flat_map(|seed_range| seed_range.clone())
             .map(|seed| self.maps.iter().fold(seed, |acc, map| map.lookup(acc)))
     }
+
+    fn lookup_ranges(&self) -> Vec<Range<u64>> {
+        self.maps.iter().fold(self.seeds.clone(), |ranges, map| {
+            ranges
+                .into_iter()
+                .flat_map(|range| map.lookup_range(range))
+                .collect()
+        })
+    }
 }
 
 fn parse_seeds(input: &str) -> IResult<&str, Vec<Range<u64>>> {
@@ -86,6 +130,7 @@ fn parse_seeds(input: &str) -> IResult<&str, Vec<Range<u64>>> {
                 seed_ranges
                     .into_iter()
                     .map(|(start, length)| start..(start + length))
+                    .filter(|seed_range| !seed_range.is_empty())
                     .collect(),
             )
         })
@@ -110,8 +155,10 @@ fn parse_range_mapping(input: &str) -> IResult<&str, RangeMapping> {
 }
 
 fn parse_map(input: &str) -> IResult<&str, Map> {
-    take_until("map:")
-        .precedes(tag("map:"))
+    // Header of the map, e.g. `seed-to-soil map:`
+    multispace1
+        .precedes(is_not(" \r\n"))
+        .precedes(tag(" map:"))
         .precedes(many1(line_ending.precedes(parse_range_mapping)))
         .parse(input)
         .map(|(input, mappings)| (input, Map { mappings }))
@@ -125,21 +172,34 @@ fn parse_almanac(input: &str) -> IResult<&str, Almanac> {
 
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<u64, AocError> {
-    let (_, almanac) = parse_almanac(input).unwrap();
+    let almanac = parse_complete(input, parse_almanac)?;
+
+    almanac
+        .lookup_ranges()
+        .iter()
+        .filter(|range| !range.is_empty())
+        .map(|range| range.start)
+        .min()
+        .ok_or_else(|| AocError::NoSolution("there are no seeds to plant".to_owned()))
+}
+
+/// Same as [`process`] but looking up every single seed, only useful to cross-check the range based lookup
+#[cfg(feature = "brute-force")]
+#[tracing::instrument]
+pub fn process_brute_force(input: &str) -> miette::Result<u64, AocError> {
+    let almanac = parse_complete(input, parse_almanac)?;
 
-    Ok(almanac
+    almanac
         .lookup()
         .min()
-        .expect("List of final locations should not be empty!"))
+        .ok_or_else(|| AocError::NoSolution("there are no seeds to plant".to_owned()))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_process() -> miette::Result<()> {
-        let input = "seeds: 79 14 55 13
+    const INPUT: &str = "seeds: 79 14 55 13
 
 seed-to-soil map:
 50 98 2
@@ -173,7 +233,44 @@ humidity-to-location map:
 60 56 37
 56 93 4
 ";
-        assert_eq!(46, process(input)?);
+
+    #[test]
+    fn test_lookup_range() {
+        let map = Map {
+            mappings: vec![
+                RangeMapping {
+                    destination_start: 50,
+                    source_start: 98,
+                    length: 2,
+                },
+                RangeMapping {
+                    destination_start: 52,
+                    source_start: 50,
+                    length: 48,
+                },
+            ],
+        };
+        let mut ranges = map.lookup_range(40..100);
+        ranges.sort_by_key(|range| range.start);
+        assert_eq!(vec![40..50, 50..52, 52..100], ranges);
+    }
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        assert_eq!(46, process(INPUT)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_no_seeds() {
+        let input = INPUT.replacen("seeds: 79 14 55 13", "seeds: 79 0", 1);
+        assert!(matches!(process(&input), Err(AocError::NoSolution(_))));
+    }
+
+    #[cfg(feature = "brute-force")]
+    #[test]
+    fn test_process_brute_force() -> miette::Result<()> {
+        assert_eq!(process(INPUT)?, process_brute_force(INPUT)?);
         Ok(())
     }
 }
@@ -1,4 +1,4 @@
-use crate::custom_error::AocError;
+use aoc_common::{custom_error::AocError, parsing::parse_complete};
 use nom::character::complete::{self, line_ending, space1};
 use nom::multi::separated_list1;
 use nom::sequence::{separated_pair, tuple};
@@ -14,18 +14,49 @@ struct Race {
 }
 
 impl Race {
+    // Whether holding the button for `hold` milliseconds beats the record
+    fn wins(&self, hold: u64) -> bool {
+        let distance = hold as u128 * (self.time - hold) as u128;
+        distance > self.dist_record as u128
+    }
+
     // The margin of error of a race is defined as how many ways there is to beat the best distance
-    fn margin_of_error(&self) -> u64 {
-        let length = ((self.time.pow(2) - 4 * self.dist_record) as f64).sqrt();
-        let length_floor = length.floor();
-        let range_start = (self.time as f64 - length) / 2.0;
-
-        match (length - length_floor) + (range_start - range_start.floor()) {
-            test if test == 0.0 => length_floor as u64 - 1,
-            test if test < 1.0 => length_floor as u64,
-            _ => length_floor as u64 + 1,
+    //
+    // Winning hold times `h` satisfy `h * (time - h) > dist_record`, i.e. they lie strictly between
+    // the roots of `h^2 - time * h + dist_record`, which are symmetric around `time / 2`.
+    fn margin_of_error(&self) -> Result<u64, AocError> {
+        let time = self.time as u128;
+        let discriminant = (time * time).checked_sub(4 * self.dist_record as u128);
+
+        // First guess for the shortest winning hold time, which never overshoots the actual one
+        let mut hold = discriminant.map(|d| ((time - isqrt(d)) / 2) as u64);
+        while let Some(h) = hold.filter(|&h| h <= self.time / 2 && !self.wins(h)) {
+            hold = Some(h + 1);
         }
+
+        match hold.filter(|&h| h <= self.time / 2) {
+            Some(h) => Ok(self.time - 2 * h + 1),
+            None => Err(AocError::NoSolution(format!(
+                "the record of {} can not be beaten in a race lasting {}",
+                self.dist_record, self.time
+            ))),
+        }
+    }
+}
+
+// Largest integer whose square is not greater than `n`
+fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+    // Newton's method starting from above the root, which decreases until reaching it
+    let mut x = n / 2 + 1;
+    let mut y = (x + n / x) / 2;
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
     }
+    x
 }
 
 fn parse_input(input: &str) -> IResult<&str, Vec<Race>> {
@@ -48,14 +79,68 @@ fn parse_input(input: &str) -> IResult<&str, Vec<Race>> {
 
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<u64, AocError> {
-    let (_, races) = parse_input(input).unwrap();
+    let races = parse_complete(input, parse_input)?;
 
-    Ok(races.iter().map(|race| race.margin_of_error()).product())
+    races.iter().map(Race::margin_of_error).product()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    // Count every winning hold time one by one
+    fn brute_force(race: &Race) -> u64 {
+        (0..=race.time).filter(|&hold| race.wins(hold)).count() as u64
+    }
+
+    #[rstest]
+    #[case(7, 9, 4)]
+    #[case(15, 40, 8)]
+    #[case(30, 200, 9)]
+    fn test_margin_of_error(
+        #[case] time: u64,
+        #[case] dist_record: u64,
+        #[case] expected: u64,
+    ) -> miette::Result<()> {
+        assert_eq!(expected, Race { time, dist_record }.margin_of_error()?);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(4, 4)]
+    #[case(4, 100)]
+    #[case(0, 0)]
+    fn test_margin_of_error_no_solution(#[case] time: u64, #[case] dist_record: u64) {
+        assert!(Race { time, dist_record }.margin_of_error().is_err());
+    }
+
+    #[test]
+    fn test_margin_of_error_large_race() -> miette::Result<()> {
+        // The discriminant does not fit in a `u64`
+        let race = Race {
+            time: u64::MAX / 2,
+            dist_record: u64::MAX,
+        };
+        let winning = race.margin_of_error()?;
+        let shortest = (race.time + 1 - winning) / 2;
+        assert!(race.wins(shortest) && !race.wins(shortest - 1));
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn test_margin_of_error_matches_brute_force(
+            (time, dist_record) in (0u64..2000).prop_flat_map(|time| (Just(time), 0..=time * time / 4 + 2))
+        ) {
+            let race = Race { time, dist_record };
+            match race.margin_of_error() {
+                Ok(winning) => prop_assert_eq!(brute_force(&race), winning),
+                Err(_) => prop_assert_eq!(0, brute_force(&race)),
+            }
+        }
+    }
 
     #[test]
     fn test_process() -> miette::Result<()> {
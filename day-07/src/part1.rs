@@ -1,13 +1,18 @@
 use itertools::Itertools;
-use nom::character::complete::{self, line_ending, one_of, space1};
-use nom::multi::{many1, separated_list1};
-use nom::{sequence::separated_pair, IResult};
+use nom::character::complete::{self, anychar, space1};
+use nom::combinator::map_res;
+use nom::multi::count;
+use nom::{sequence::separated_pair, IResult, Parser};
 use std::cmp::{Ordering, Reverse};
 use std::iter::zip;
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
+use thiserror::Error;
 
-use crate::custom_error::AocError;
+use aoc_common::{
+    custom_error::AocError,
+    parsing::{lines, parse_complete},
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, EnumIter)]
 enum Card {
@@ -26,9 +31,15 @@ enum Card {
     A,
 }
 
-impl Card {
-    fn from_char(c: &char) -> Self {
-        match c {
+#[derive(Debug, Error)]
+#[error("unrecognized card `{0}`")]
+struct UnrecognizedCard(char);
+
+impl TryFrom<char> for Card {
+    type Error = UnrecognizedCard;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Ok(match c {
             '2' => Card::N2,
             '3' => Card::N3,
             '4' => Card::N4,
@@ -42,13 +53,13 @@ impl Card {
             'Q' => Card::Q,
             'K' => Card::K,
             'A' => Card::A,
-            _ => panic!("Unrecognized card character!"),
-        }
+            _ => return Err(UnrecognizedCard(c)),
+        })
     }
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct Hand {
+pub struct Hand {
     cards: [Card; 5],
     bid: u32,
 }
@@ -92,15 +103,7 @@ impl Hand {
 
 impl PartialOrd for Hand {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        match self.get_type().partial_cmp(&other.get_type()) {
-            Some(Ordering::Equal) => Some(
-                zip(&self.cards, &other.cards)
-                    .find(|(self_card, other_card)| self_card != other_card)
-                    .map(|(self_card, other_card)| self_card.cmp(other_card))
-                    .unwrap_or(Ordering::Equal),
-            ),
-            x => x,
-        }
+        Some(self.cmp(other))
     }
 }
 
@@ -117,39 +120,34 @@ impl Ord for Hand {
     }
 }
 
+fn parse_card(input: &str) -> IResult<&str, Card> {
+    map_res(anychar, Card::try_from)(input)
+}
+
 fn parse_hand(input: &str) -> IResult<&str, Hand> {
-    separated_pair(many1(one_of("23456789TJQKA")), space1, complete::u32)(input).map(
-        |(input, (cards, bid))| {
-            (
-                input,
-                Hand {
-                    cards: cards
-                        .iter()
-                        .map(Card::from_char)
-                        .collect::<Vec<_>>()
-                        .try_into()
-                        .unwrap(),
-                    bid,
-                },
-            )
-        },
+    separated_pair(
+        map_res(count(parse_card, 5), <[Card; 5]>::try_from),
+        space1,
+        complete::u32,
     )
+    .map(|(cards, bid)| Hand { cards, bid })
+    .parse(input)
 }
 
 fn parse_hands(input: &str) -> IResult<&str, Vec<Hand>> {
-    separated_list1(line_ending, parse_hand)(input)
+    lines(parse_hand)(input)
 }
 
 #[tracing::instrument]
 pub fn process_line(line: &str) -> miette::Result<Hand, AocError> {
-    let (_, hand) = parse_hand(line).unwrap();
+    let hand = parse_complete(line, parse_hand)?;
 
     Ok(hand)
 }
 
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<u32, AocError> {
-    let (_, hands) = parse_hands(input).expect("Sample input parsing should work!");
+    let hands = parse_complete(input, parse_hands)?;
 
     let result = hands
         .iter()
@@ -177,6 +175,14 @@ mod tests {
         Ok(())
     }
 
+    #[rstest]
+    #[case("32T3X 765")]
+    #[case("32T3 765")]
+    #[case("32T3KK 765")]
+    fn test_invalid_lines(#[case] line: &str) {
+        assert!(process_line(line).is_err());
+    }
+
     #[test]
     fn test_process() -> miette::Result<()> {
         let input = "32T3K 765
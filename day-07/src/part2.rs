@@ -1,13 +1,18 @@
 use itertools::Itertools;
-use nom::character::complete::{self, line_ending, one_of, space1};
-use nom::multi::{many1, separated_list1};
-use nom::{sequence::separated_pair, IResult};
+use nom::character::complete::{self, anychar, space1};
+use nom::combinator::map_res;
+use nom::multi::count;
+use nom::{sequence::separated_pair, IResult, Parser};
 use std::cmp::{Ordering, Reverse};
 use std::iter::zip;
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
+use thiserror::Error;
 
-use crate::custom_error::AocError;
+use aoc_common::{
+    custom_error::AocError,
+    parsing::{lines, parse_complete},
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, EnumIter)]
 enum CardLabel {
@@ -26,9 +31,15 @@ enum CardLabel {
     A,
 }
 
-impl CardLabel {
-    fn from_char(c: &char) -> Self {
-        match c {
+#[derive(Debug, Error)]
+#[error("unrecognized card `{0}`")]
+struct UnrecognizedCard(char);
+
+impl TryFrom<char> for CardLabel {
+    type Error = UnrecognizedCard;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Ok(match c {
             '2' => CardLabel::N2,
             '3' => CardLabel::N3,
             '4' => CardLabel::N4,
@@ -42,13 +53,13 @@ impl CardLabel {
             'Q' => CardLabel::Q,
             'K' => CardLabel::K,
             'A' => CardLabel::A,
-            _ => panic!("Unrecognized card character!"),
-        }
+            _ => return Err(UnrecognizedCard(c)),
+        })
     }
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct Hand {
+pub struct Hand {
     cards: [CardLabel; 5],
     bid: u32,
 }
@@ -110,15 +121,7 @@ impl Hand {
 
 impl PartialOrd for Hand {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        match self.get_type().partial_cmp(&other.get_type()) {
-            Some(Ordering::Equal) => Some(
-                zip(&self.cards, &other.cards)
-                    .find(|(self_card, other_card)| self_card != other_card)
-                    .map(|(self_card, other_card)| self_card.cmp(other_card))
-                    .unwrap_or(Ordering::Equal),
-            ),
-            x => x,
-        }
+        Some(self.cmp(other))
     }
 }
 
@@ -135,39 +138,34 @@ impl Ord for Hand {
     }
 }
 
+fn parse_card(input: &str) -> IResult<&str, CardLabel> {
+    map_res(anychar, CardLabel::try_from)(input)
+}
+
 fn parse_hand(input: &str) -> IResult<&str, Hand> {
-    separated_pair(many1(one_of("23456789TJQKA")), space1, complete::u32)(input).map(
-        |(input, (cards, bid))| {
-            (
-                input,
-                Hand {
-                    cards: cards
-                        .iter()
-                        .map(CardLabel::from_char)
-                        .collect::<Vec<_>>()
-                        .try_into()
-                        .unwrap(),
-                    bid,
-                },
-            )
-        },
+    separated_pair(
+        map_res(count(parse_card, 5), <[CardLabel; 5]>::try_from),
+        space1,
+        complete::u32,
     )
+    .map(|(cards, bid)| Hand { cards, bid })
+    .parse(input)
 }
 
 fn parse_hands(input: &str) -> IResult<&str, Vec<Hand>> {
-    separated_list1(line_ending, parse_hand)(input)
+    lines(parse_hand)(input)
 }
 
 #[tracing::instrument]
 pub fn process_line(line: &str) -> miette::Result<Hand, AocError> {
-    let (_, hand) = parse_hand(line).unwrap();
+    let hand = parse_complete(line, parse_hand)?;
 
     Ok(hand)
 }
 
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<u32, AocError> {
-    let (_, hands) = parse_hands(input).expect("Sample input parsing should work!");
+    let hands = parse_complete(input, parse_hands)?;
 
     let result = hands
         .iter()
@@ -195,6 +193,14 @@ mod tests {
         Ok(())
     }
 
+    #[rstest]
+    #[case("32T3X 765")]
+    #[case("32T3 765")]
+    #[case("32T3KK 765")]
+    fn test_invalid_lines(#[case] line: &str) {
+        assert!(process_line(line).is_err());
+    }
+
     #[test]
     fn test_process() -> miette::Result<()> {
         let input = "32T3K 765
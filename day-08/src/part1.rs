@@ -8,7 +8,7 @@ use nom::{
 use nom_supreme::ParserExt;
 use std::collections::HashMap;
 
-use crate::custom_error::AocError;
+use aoc_common::{custom_error::AocError, parsing::parse_complete};
 
 #[derive(Debug)]
 #[repr(u8)]
@@ -88,7 +88,7 @@ fn parse_instructions(input: &str) -> IResult<&str, Vec<Instruction>> {
     })
 }
 
-fn parse_node_info(input: &str) -> IResult<&str, (Node, (Node, Node))> {
+fn parse_node_info(input: &str) -> IResult<&str, (Node<'_>, (Node<'_>, Node<'_>))> {
     tuple((
         alpha1,
         delimited(
@@ -108,7 +108,7 @@ fn parse_node_info(input: &str) -> IResult<&str, (Node, (Node, Node))> {
     // })
 }
 
-fn parse_network(input: &str) -> IResult<&str, Network> {
+fn parse_network(input: &str) -> IResult<&str, Network<'_>> {
     separated_list1(line_ending, parse_node_info)
         .preceded_by(many0(line_ending))
         .parse(input)
@@ -126,7 +126,7 @@ fn parse_network(input: &str) -> IResult<&str, Network> {
         })
 }
 
-fn parse_map(input: &str) -> IResult<&str, Map> {
+fn parse_map(input: &str) -> IResult<&str, Map<'_>> {
     separated_pair(parse_instructions, many1(line_ending), parse_network)(input).map(
         |(input, (instructions, network))| {
             (
@@ -140,9 +140,30 @@ fn parse_map(input: &str) -> IResult<&str, Map> {
     )
 }
 
+// Every left/right target must be a node of the network, the first undefined one is reported
+fn check_network(input: &str, network: &Network) -> Result<(), AocError> {
+    match network
+        .values()
+        .flatten()
+        .filter(|target| !network.contains_key(*target))
+        .min_by_key(|target| target.as_ptr())
+    {
+        Some(target) => Err(AocError::parse_error(
+            input,
+            target,
+            format!("undefined node `{target}`"),
+        )),
+        None => Ok(()),
+    }
+}
+
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<usize, AocError> {
-    let (_, map) = parse_map(input).expect("Sample input should parse!");
+    let map = parse_complete(input, parse_map)?;
+    check_network(input, &map.network)?;
+    if !map.network.contains_key("AAA") {
+        return Err(AocError::NoSolution("there is no `AAA` node".to_owned()));
+    }
 
     let navigator = MapNavigator::new(&map);
     // dbg!(navigator.collect::<Vec<_>>());
@@ -170,4 +191,22 @@ ZZZ = (ZZZ, ZZZ)";
         assert_eq!(2, process(input)?);
         Ok(())
     }
+
+    #[test]
+    fn test_undefined_node() {
+        let input = "L
+
+AAA = (BBB, BBB)
+ZZZ = (ZZZ, ZZZ)";
+        assert!(matches!(process(input), Err(AocError::ParseError { .. })));
+    }
+
+    #[test]
+    fn test_no_starting_node() {
+        let input = "L
+
+BBB = (ZZZ, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+        assert!(matches!(process(input), Err(AocError::NoSolution(_))));
+    }
 }
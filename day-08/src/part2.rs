@@ -1,4 +1,4 @@
-use crate::custom_error::AocError;
+use aoc_common::{custom_error::AocError, parsing::parse_complete};
 use nom::{
     bytes::complete::tag,
     character::complete::{alphanumeric1, line_ending, one_of},
@@ -43,49 +43,138 @@ impl<'a> MapNavigator<'a> {
     }
 }
 
+// Navigators never stop, they yield their current state (instruction counter and node) at every step
 impl<'a> Iterator for MapNavigator<'a> {
-    type Item = Node<'a>;
+    type Item = (usize, Node<'a>);
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.node.ends_with("Z") {
-            None
-        } else {
-            // Go Left/Right
-            let step = &self.map.instructions[self.counter];
-            // Update current node
-            let idx = match step {
-                Instruction::Left => 0,
-                Instruction::Right => 1,
-            };
-            self.node = self.map.network[self.node][idx];
-            // Update counter and wrap if necessary
-            self.counter += 1;
-            if self.counter == self.map.instructions.len() {
-                self.counter = 0;
+        let state = (self.counter, self.node);
+        // Go Left/Right
+        let step = &self.map.instructions[self.counter];
+        // Update current node
+        let idx = match step {
+            Instruction::Left => 0,
+            Instruction::Right => 1,
+        };
+        self.node = self.map.network[self.node][idx];
+        // Update counter and wrap if necessary
+        self.counter += 1;
+        if self.counter == self.map.instructions.len() {
+            self.counter = 0;
+        }
+        // Return previous state
+        Some(state)
+    }
+}
+
+/// Steps at which a navigator stands on a `..Z` node
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Arrivals {
+    // Arrivals happening only once, before the navigator enters its cycle
+    once: Vec<u64>,
+    // Arrivals repeating forever, at `start + k * period` for every `k >= 0`
+    starts: Vec<u64>,
+    period: u64,
+}
+
+impl Arrivals {
+    // Walk the map until a state repeats, recording the arrivals on the way
+    fn of_navigator(mut navigator: MapNavigator) -> Self {
+        let mut seen = HashMap::new();
+        let mut hits = Vec::new();
+        for step in 0.. {
+            let state = navigator.next().expect("Navigators never stop!");
+            if let Some(&cycle_start) = seen.get(&state) {
+                let (once, starts) = hits.into_iter().partition(|&hit| hit < cycle_start);
+                return Self {
+                    once,
+                    starts,
+                    period: step - cycle_start,
+                };
+            }
+            seen.insert(state, step);
+            if state.1.ends_with('Z') {
+                hits.push(step);
             }
-            // Return new state
-            Some(self.node)
         }
+        unreachable!("The number of states is finite!")
+    }
+
+    fn contains(&self, step: u64) -> bool {
+        self.once.contains(&step)
+            || self
+                .starts
+                .iter()
+                .any(|&start| step >= start && step % self.period == start % self.period)
+    }
+
+    // Steps at which both navigators arrive at the same time, if their combined period fits in a `u64`
+    fn intersect(&self, other: &Self) -> Option<Self> {
+        let mut once: Vec<u64> = self
+            .once
+            .iter()
+            .filter(|&&step| other.contains(step))
+            .chain(other.once.iter().filter(|&&step| self.contains(step)))
+            .copied()
+            .collect();
+        once.sort_unstable();
+        once.dedup();
+
+        let period = lcm(self.period, other.period)?;
+        let mut starts: Vec<u64> = self
+            .starts
+            .iter()
+            .flat_map(|&a| {
+                other.starts.iter().filter_map(move |&b| {
+                    let first = crt(a, self.period, b, other.period)?;
+                    // Both progressions only begin at their start
+                    let min = a.max(b);
+                    Some(first + min.saturating_sub(first).div_ceil(period) * period)
+                })
+            })
+            .collect();
+        starts.sort_unstable();
+        starts.dedup();
+
+        Some(Self {
+            once,
+            starts,
+            period,
+        })
+    }
+
+    fn first(&self) -> Option<u64> {
+        self.once.iter().chain(self.starts.iter()).min().copied()
     }
 }
 
-// TODO: Find a better algortihm for GCD and LCM
-fn gcd(a: usize, b: usize) -> usize {
+// Greatest common divisor along with Bézout coefficients: `a * x + b * y = gcd(a, b)`
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
     match b {
-        0 => a,
-        _ => gcd(b, a % b),
+        0 => (a, 1, 0),
+        _ => {
+            let (g, x, y) = extended_gcd(b, a % b);
+            (g, y, x - (a / b) * y)
+        }
     }
 }
 
-fn lcm(nums: &[usize]) -> usize {
-    match nums {
-        [n] => *n,
-        [n, ns @ ..] => {
-            let m = lcm(ns);
-            (n * m) / gcd(*n, m)
-        }
-        [] => panic!("Least Common Multiplier of empty vector!"),
+fn lcm(a: u64, b: u64) -> Option<u64> {
+    let (g, _, _) = extended_gcd(a as i128, b as i128);
+    (a / g as u64).checked_mul(b)
+}
+
+// Smallest non-negative `x` such that `x = a (mod p)` and `x = b (mod q)`, moduli need not be coprime
+fn crt(a: u64, p: u64, b: u64, q: u64) -> Option<u64> {
+    let (a, p, b, q) = (a as i128, p as i128, b as i128, q as i128);
+    let (g, x, _) = extended_gcd(p, q);
+    if (b - a) % g != 0 {
+        return None;
     }
+    // `p * x = g (mod q)`, so `a + p * k` with `k = (b - a) / g * x` is a solution
+    let l = p / g * q;
+    let k = ((b - a) / g * x).rem_euclid(q / g);
+    Some((a + p * k).rem_euclid(l) as u64)
 }
 
 fn parse_instructions(input: &str) -> IResult<&str, Vec<Instruction>> {
@@ -104,7 +193,7 @@ fn parse_instructions(input: &str) -> IResult<&str, Vec<Instruction>> {
     })
 }
 
-fn parse_node_info(input: &str) -> IResult<&str, (Node, (Node, Node))> {
+fn parse_node_info(input: &str) -> IResult<&str, (Node<'_>, (Node<'_>, Node<'_>))> {
     tuple((
         alphanumeric1,
         delimited(
@@ -124,7 +213,7 @@ fn parse_node_info(input: &str) -> IResult<&str, (Node, (Node, Node))> {
     // })
 }
 
-fn parse_network(input: &str) -> IResult<&str, Network> {
+fn parse_network(input: &str) -> IResult<&str, Network<'_>> {
     separated_list1(line_ending, parse_node_info)
         .preceded_by(many0(line_ending))
         .parse(input)
@@ -142,7 +231,7 @@ fn parse_network(input: &str) -> IResult<&str, Network> {
         })
 }
 
-fn parse_map(input: &str) -> IResult<&str, Map> {
+fn parse_map(input: &str) -> IResult<&str, Map<'_>> {
     separated_pair(parse_instructions, many1(line_ending), parse_network)(input).map(
         |(input, (instructions, network))| {
             (
@@ -156,9 +245,27 @@ fn parse_map(input: &str) -> IResult<&str, Map> {
     )
 }
 
+// Every left/right target must be a node of the network, the first undefined one is reported
+fn check_network(input: &str, network: &Network) -> Result<(), AocError> {
+    match network
+        .values()
+        .flatten()
+        .filter(|target| !network.contains_key(*target))
+        .min_by_key(|target| target.as_ptr())
+    {
+        Some(target) => Err(AocError::parse_error(
+            input,
+            target,
+            format!("undefined node `{target}`"),
+        )),
+        None => Ok(()),
+    }
+}
+
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<usize, AocError> {
-    let (_, map) = parse_map(input).expect("Sample input should parse!");
+    let map = parse_complete(input, parse_map)?;
+    check_network(input, &map.network)?;
 
     let starting_nodes: Vec<_> = map
         .network
@@ -167,17 +274,31 @@ pub fn process(input: &str) -> miette::Result<usize, AocError> {
         .copied()
         .collect();
 
-    let steps: Vec<_> = starting_nodes
+    let arrivals: Vec<_> = starting_nodes
         .par_iter()
-        .map(|node| MapNavigator::new(&map, node).count())
+        .map(|node| Arrivals::of_navigator(MapNavigator::new(&map, node)))
         .collect();
 
-    Ok(lcm(&steps))
+    arrivals
+        .into_iter()
+        .map(Some)
+        .reduce(|acc, other| {
+            acc.zip(other)
+                .and_then(|(acc, other)| acc.intersect(&other))
+        })
+        .ok_or_else(|| AocError::NoSolution("no ghost starts on a `..A` node".to_owned()))?
+        .ok_or_else(|| AocError::NoSolution("the ghosts' combined period overflows".to_owned()))?
+        .first()
+        .map(|step| step as usize)
+        .ok_or_else(|| {
+            AocError::NoSolution("the ghosts never stand on `..Z` nodes all at once".to_owned())
+        })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rstest::rstest;
 
     #[test]
     fn test_process() -> miette::Result<()> {
@@ -194,4 +315,87 @@ XXX = (XXX, XXX)";
         assert_eq!(6, process(input)?);
         Ok(())
     }
+
+    #[rstest]
+    // Ghosts with cycles of different lengths, whose arrivals are not aligned with their periods
+    #[case(
+        "L
+
+11A = (11B, 11B)
+11B = (11Z, 11Z)
+11Z = (11B, 11B)
+22A = (22Z, 22Z)
+22Z = (22B, 22B)
+22B = (22C, 22C)
+22C = (22Z, 22Z)",
+        4
+    )]
+    // Arrival happening only once, before entering the cycle
+    #[case(
+        "L
+
+22A = (22Z, 22Z)
+22Z = (22B, 22B)
+22B = (22Z, 22Z)
+33A = (33Z, 33Z)
+33Z = (33X, 33X)
+33X = (33X, 33X)",
+        1
+    )]
+    fn test_unaligned_cycles(#[case] input: &str, #[case] expected: usize) -> miette::Result<()> {
+        assert_eq!(expected, process(input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_no_simultaneous_arrival() {
+        let input = "L
+
+11A = (11B, 11B)
+11B = (11Z, 11Z)
+11Z = (11B, 11B)
+22A = (22Z, 22Z)
+22Z = (22B, 22B)
+22B = (22Z, 22Z)";
+        assert!(process(input).is_err());
+    }
+
+    #[test]
+    fn test_no_starting_node() {
+        let input = "L
+
+11B = (11Z, 11Z)
+11Z = (11B, 11B)";
+        assert!(matches!(process(input), Err(AocError::NoSolution(_))));
+    }
+
+    #[rstest]
+    #[case(4, 6, Some(12))]
+    #[case(u64::MAX, u64::MAX - 1, None)]
+    fn test_lcm(#[case] a: u64, #[case] b: u64, #[case] expected: Option<u64>) {
+        assert_eq!(expected, lcm(a, b));
+    }
+
+    #[rstest]
+    #[case(2, 4, 3, 9, Some(30))]
+    #[case(1, 2, 0, 4, None)]
+    #[case(0, 6, 4, 10, Some(24))]
+    fn test_crt(
+        #[case] a: u64,
+        #[case] p: u64,
+        #[case] b: u64,
+        #[case] q: u64,
+        #[case] expected: Option<u64>,
+    ) {
+        assert_eq!(expected, crt(a, p, b, q));
+    }
+
+    #[test]
+    fn test_undefined_node() {
+        let input = "L
+
+AAA = (BBB, BBB)
+ZZZ = (ZZZ, ZZZ)";
+        assert!(matches!(process(input), Err(AocError::ParseError { .. })));
+    }
 }
@@ -1,66 +1,19 @@
-use crate::custom_error::AocError;
-use ndarray::Array2;
-use nom::{
-    character::complete::{line_ending, one_of},
-    multi::{many1, separated_list1},
-    IResult,
+use aoc_common::{
+    custom_error::AocError,
+    grid::{Direction, Grid, UnrecognizedChar},
+    parsing::{grid, parse_complete},
 };
-use std::fmt::Debug;
+use nom::{combinator::map_opt, IResult};
 
-#[derive(Debug, Clone, Copy)]
-enum Direction {
-    North,
-    South,
-    East,
-    West,
-}
-
-impl Direction {
-    fn possible_next_tiles(&self) -> [Tile; 3] {
-        match self {
-            Direction::East => [Tile::Horizontal, Tile::TurnNW, Tile::TurnSW],
-            Direction::North => [Tile::Vertical, Tile::TurnSW, Tile::TurnSE],
-            Direction::South => [Tile::Vertical, Tile::TurnNE, Tile::TurnNW],
-            Direction::West => [Tile::Horizontal, Tile::TurnNE, Tile::TurnSE],
-        }
+fn possible_next_tiles(direction: &Direction) -> [Tile; 3] {
+    match direction {
+        Direction::East => [Tile::Horizontal, Tile::TurnNW, Tile::TurnSW],
+        Direction::North => [Tile::Vertical, Tile::TurnSW, Tile::TurnSE],
+        Direction::South => [Tile::Vertical, Tile::TurnNE, Tile::TurnNW],
+        Direction::West => [Tile::Horizontal, Tile::TurnNE, Tile::TurnSE],
     }
 }
 
-fn get_nearest_neighbors<T>(
-    lattice: &Array2<T>,
-    &[y, x]: &[usize; 2],
-) -> [(Direction, Option<T>); 4]
-where
-    T: Clone + Copy + Debug,
-{
-    let maybe_idx_north = y.checked_sub(1).map(|y_north| [y_north, x]);
-    let maybe_idx_west = x.checked_sub(1).map(|x_west| [y, x_west]);
-    let idx_south = [y + 1, x];
-    let idx_east = [y, x + 1];
-
-    [
-        Direction::North,
-        Direction::West,
-        Direction::South,
-        Direction::East,
-    ]
-    .iter()
-    .copied()
-    .zip(
-        [maybe_idx_north, maybe_idx_west]
-            .iter()
-            .map(|maybe_idx| maybe_idx.map(|idx| lattice[idx]))
-            .chain(
-                [idx_south, idx_east]
-                    .iter()
-                    .map(|idx| lattice.get(*idx).copied()),
-            ),
-    )
-    .collect::<Vec<_>>()
-    .try_into()
-    .unwrap()
-}
-
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Tile {
     Start,      // S
@@ -73,52 +26,56 @@ enum Tile {
     TurnSE,     // F
 }
 
-impl Tile {
-    fn from_char(c: &char) -> Self {
+impl TryFrom<char> for Tile {
+    type Error = UnrecognizedChar;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
         match c {
-            'S' => Self::Start,
-            '.' => Self::Ground,
-            '|' => Self::Vertical,
-            '-' => Self::Horizontal,
-            'L' => Self::TurnNE,
-            'J' => Self::TurnNW,
-            '7' => Self::TurnSW,
-            'F' => Self::TurnSE,
-            _ => panic!("Unrecognized tile character!"),
+            'S' => Ok(Self::Start),
+            '.' => Ok(Self::Ground),
+            '|' => Ok(Self::Vertical),
+            '-' => Ok(Self::Horizontal),
+            'L' => Ok(Self::TurnNE),
+            'J' => Ok(Self::TurnNW),
+            '7' => Ok(Self::TurnSW),
+            'F' => Ok(Self::TurnSE),
+            _ => Err(UnrecognizedChar(c)),
         }
     }
+}
 
+impl Tile {
     // Given that we have arrived at this tile with `current_direction`, in what direction will we turn to
     // `Start` position is special for it always preserve direction
-    fn next_direction(&self, current_direction: &Direction) -> Direction {
+    fn next_direction(&self, current_direction: &Direction) -> Option<Direction> {
         match current_direction {
             Direction::North => match self {
-                Self::Start => Direction::North,
-                Self::Vertical => Direction::North,
-                Self::TurnSW => Direction::West,
-                Self::TurnSE => Direction::East,
-                _ => panic!("Impossible current direction!"),
+                Self::Start => Some(Direction::North),
+                Self::Vertical => Some(Direction::North),
+                Self::TurnSW => Some(Direction::West),
+                Self::TurnSE => Some(Direction::East),
+                _ => None,
             },
             Direction::South => match self {
-                Self::Start => Direction::South,
-                Self::Vertical => Direction::South,
-                Self::TurnNW => Direction::West,
-                Self::TurnNE => Direction::East,
-                _ => panic!("Impossible current direction!"),
+                Self::Start => Some(Direction::South),
+                Self::Vertical => Some(Direction::South),
+                Self::TurnNW => Some(Direction::West),
+                Self::TurnNE => Some(Direction::East),
+                _ => None,
             },
             Direction::East => match self {
-                Self::Start => Direction::East,
-                Self::Horizontal => Direction::East,
-                Self::TurnNW => Direction::North,
-                Self::TurnSW => Direction::South,
-                _ => panic!("Impossible current direction!"),
+                Self::Start => Some(Direction::East),
+                Self::Horizontal => Some(Direction::East),
+                Self::TurnNW => Some(Direction::North),
+                Self::TurnSW => Some(Direction::South),
+                _ => None,
             },
             Direction::West => match self {
-                Self::Start => Direction::West,
-                Self::Horizontal => Direction::West,
-                Self::TurnNE => Direction::North,
-                Self::TurnSE => Direction::South,
-                _ => panic!("Impossible current direction!"),
+                Self::Start => Some(Direction::West),
+                Self::Horizontal => Some(Direction::West),
+                Self::TurnNE => Some(Direction::North),
+                Self::TurnSE => Some(Direction::South),
+                _ => None,
             },
         }
     }
@@ -126,8 +83,8 @@ impl Tile {
 
 #[derive(Debug)]
 struct Maze {
-    // 2D array of tiles
-    tiles: Array2<Tile>,
+    // 2D grid of tiles
+    tiles: Grid<Tile>,
     // Coordinates of the starting tile
     start_idx: [usize; 2],
     // The two possible directions we can start walking
@@ -135,36 +92,30 @@ struct Maze {
 }
 
 impl Maze {
-    fn from_parsed_input(arr: Vec<Vec<char>>) -> Self {
-        let nrows = arr.len();
-        let ncols = arr[0].len();
-        let data = arr.iter().flatten().map(Tile::from_char).collect();
-
-        let tiles = Array2::from_shape_vec([nrows, ncols], data).unwrap();
+    // Maze with a single starting tile connected to exactly two pipes, whose loop leads back to it
+    fn new(tiles: Grid<Tile>) -> Option<Self> {
+        let start_idx = tiles.position(|&tile| tile == Tile::Start)?;
 
-        let start_idx: [usize; 2] = tiles
-            .indexed_iter()
-            .find(|(_, &tile)| tile == Tile::Start)
-            .map(|(idx, _)| idx)
-            .expect("There should be an starting tile!")
-            .into();
-
-        let start_directions: [Direction; 2] = get_nearest_neighbors(&tiles, &start_idx)
-            .iter()
-            .filter(|(direction, maybe_tile)| match maybe_tile {
-                Some(tile) => direction.possible_next_tiles().contains(tile),
-                None => false,
-            })
-            .map(|(direction, _)| *direction)
+        let start_directions: [Direction; 2] = tiles
+            .neighbors4(start_idx)
+            .filter(|(direction, idx)| possible_next_tiles(direction).contains(&tiles[*idx]))
+            .map(|(direction, _)| direction)
             .collect::<Vec<_>>()
             .try_into()
-            .expect("There should be exactly two possible directions to start!");
+            .ok()?;
 
-        Self {
+        let maze = Self {
             tiles,
             start_idx,
             start_directions,
-        }
+        };
+
+        // Walkers stop early on a broken loop, away from the start
+        let mut walker = Walker::on_maze(&maze, LoopDirection::A);
+        walker.by_ref().take(maze.tiles.len()).for_each(drop);
+        let closed = walker.idx == maze.start_idx;
+
+        closed.then_some(maze)
     }
 }
 
@@ -199,14 +150,9 @@ impl<'a> Walker<'a> {
         &self.maze.tiles[self.idx]
     }
 
-    fn step(&mut self, direction: &Direction) {
-        let [y, x] = self.idx;
-        self.idx = match direction {
-            Direction::North => [y - 1, x],
-            Direction::West => [y, x - 1],
-            Direction::South => [y + 1, x],
-            Direction::East => [y, x + 1],
-        }
+    fn step(&mut self, direction: &Direction) -> Option<()> {
+        self.idx = self.maze.tiles.step(self.idx, *direction)?;
+        Some(())
     }
 }
 
@@ -215,11 +161,11 @@ impl Iterator for Walker<'_> {
 
     fn next(&mut self) -> Option<Self::Item> {
         // Decide next direction based on current tile
-        let next_direction = self.current_tile().next_direction(&self.direction);
+        let next_direction = self.current_tile().next_direction(&self.direction)?;
         // Turn
         self.direction = next_direction;
         // Move
-        self.step(&next_direction);
+        self.step(&next_direction)?;
         // The iteration finishes when the loop is completed
         match self.current_tile() {
             Tile::Start => None,
@@ -229,13 +175,12 @@ impl Iterator for Walker<'_> {
 }
 
 fn parse_maze(input: &str) -> IResult<&str, Maze> {
-    separated_list1(line_ending, many1(one_of("S.|-LJ7F")))(input)
-        .map(|(input, arr)| (input, Maze::from_parsed_input(arr)))
+    map_opt(grid, Maze::new)(input)
 }
 
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<usize, AocError> {
-    let (_, maze) = parse_maze(input).unwrap();
+    let maze = parse_complete(input, parse_maze)?;
 
     // dbg!(Walker::new(&maze, LoopDirection::A)
     //     .enumerate()
@@ -244,7 +189,7 @@ pub fn process(input: &str) -> miette::Result<usize, AocError> {
     let n_steps = Walker::on_maze(&maze, LoopDirection::A)
         .zip(Walker::on_maze(&maze, LoopDirection::B))
         .position(|(position_a, position_b)| position_a == position_b)
-        .expect("The walkers must meet somewhere!");
+        .ok_or_else(|| AocError::NoSolution("the walkers never meet".to_owned()))?;
 
     // We must add one because the original problem takes into account the starting postion
     Ok(n_steps + 1)
@@ -292,4 +237,33 @@ LJ.LJ",
         assert_eq!(expected, process(input)?);
         Ok(())
     }
+
+    #[rstest]
+    // No starting tile
+    #[case(
+        ".....
+.F-7.
+.|.|.
+.L-J.
+....."
+    )]
+    // Starting tile connected to three pipes
+    #[case(
+        ".....
+.F-7.
+-S.|.
+.L-J.
+....."
+    )]
+    // Loop leaving the maze
+    #[case(
+        ".....
+.S-7.
+.|.|.
+.L-|.
+....."
+    )]
+    fn test_invalid_maze(#[case] input: &str) {
+        assert!(matches!(process(input), Err(AocError::ParseError { .. })));
+    }
 }
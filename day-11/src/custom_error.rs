@@ -1,10 +0,0 @@
-
-use miette::Diagnostic;
-use thiserror::Error;
-
-#[derive(Error, Diagnostic, Debug)]
-pub enum AocError {
-    #[error(transparent)]
-    #[diagnostic(code(aoc::io_error))]
-    IoError(#[from] std::io::Error),
-}
\ No newline at end of file
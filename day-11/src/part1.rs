@@ -1,11 +1,11 @@
-use crate::custom_error::AocError;
-use itertools::Itertools;
-use ndarray::{Array2, ArrayView2};
-use nom::{
-    character::complete::{line_ending, one_of},
-    multi::{many1, separated_list1},
-    IResult,
+use aoc_common::{
+    custom_error::AocError,
+    grid::{Grid, UnrecognizedChar},
+    parsing::{grid, parse_complete},
 };
+use itertools::Itertools;
+use ndarray::ArrayView2;
+use nom::{IResult, Parser};
 
 #[derive(Debug, PartialEq, Eq)]
 enum Element {
@@ -13,25 +13,27 @@ enum Element {
     Galaxy,
 }
 
-impl Element {
-    fn from_char(c: char) -> Self {
+impl TryFrom<char> for Element {
+    type Error = UnrecognizedChar;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
         match c {
-            '.' => Self::Space,
-            '#' => Self::Galaxy,
-            _ => panic!("Unrecognized character!"),
+            '.' => Ok(Self::Space),
+            '#' => Ok(Self::Galaxy),
+            _ => Err(UnrecognizedChar(c)),
         }
     }
 }
 
 #[derive(Debug)]
 struct Image {
-    array: Array2<Element>,
+    array: Grid<Element>,
     empty_lanes: [Vec<usize>; 2],
     galaxies: Vec<[usize; 2]>,
 }
 
 impl Image {
-    fn new(matrix: Array2<Element>) -> Self {
+    fn new(matrix: Grid<Element>) -> Self {
         let (empty_rows, empty_cols) = find_empty_space(matrix.view());
         let galaxies = matrix
             .view()
@@ -80,24 +82,12 @@ fn find_empty_space(matrix: ArrayView2<Element>) -> (Vec<usize>, Vec<usize>) {
 }
 
 fn parse_image(input: &str) -> IResult<&str, Image> {
-    separated_list1(line_ending, many1(one_of(".#")))(input).map(|(input, char_data)| {
-        debug_assert!(char_data.iter().map(|char_vec| char_vec.len()).all_equal());
-        let nested_vec = char_data
-            .into_iter()
-            .map(|char_vec| char_vec.into_iter().map(Element::from_char).collect_vec())
-            .collect_vec();
-        let nrows = nested_vec.len();
-        let ncols = nested_vec[0].len();
-        let matrix =
-            Array2::from_shape_vec((nrows, ncols), nested_vec.into_iter().flatten().collect())
-                .unwrap();
-        (input, Image::new(matrix))
-    })
+    grid.map(Image::new).parse(input)
 }
 
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<usize, AocError> {
-    let (_, image) = parse_image(input).expect("Test input should parse!");
+    let image = parse_complete(input, parse_image)?;
     // Iterate on every pair and calcuate the distance, then sum
     let result = image
         .galaxies
@@ -1,4 +1,7 @@
-use crate::custom_error::AocError;
+use aoc_common::{
+    custom_error::AocError,
+    parsing::{lines, parse_complete},
+};
 use nom::{
     bytes::complete::tag,
     character::complete::{self, one_of, space1},
@@ -45,18 +48,18 @@ struct RecordCandidate {
 }
 
 impl RecordCandidate {
-    fn new(damaged_record: &DamagedRecord) -> Self {
+    // Candidate with the smallest possible gaps, every spare operational spring being put at the end
+    fn new(damaged_record: &DamagedRecord) -> Option<Self> {
         let grouping = damaged_record.grouping.clone();
         let n = grouping.len() + 1;
         let mut fill = vec![1; n];
         fill[0] = 0;
-        fill[n - 1] = damaged_record.state.len() - grouping.iter().sum::<usize>() - n + 2;
-
-        Self { grouping, fill }
-    }
+        fill[n - 1] = damaged_record
+            .state
+            .len()
+            .checked_sub(grouping.iter().sum::<usize>() + n - 2)?;
 
-    fn len(&self) -> usize {
-        self.grouping.iter().sum::<usize>() + self.fill.iter().sum::<usize>()
+        Some(Self { grouping, fill })
     }
 
     fn state(&self) -> Vec<State> {
@@ -71,6 +74,39 @@ impl RecordCandidate {
             .chain(vec![State::Operational; *self.fill.last().unwrap()])
             .collect()
     }
+
+    fn matches(&self, damaged_record: &DamagedRecord) -> bool {
+        self.state()
+            .iter()
+            .zip(damaged_record.state.iter())
+            .all(|(state, record)| state.matches(record))
+    }
+
+    // Count the candidates matching the record obtained by moving spare operational springs
+    // from the last gap into the gaps starting at `gap`
+    fn count_matching(&mut self, damaged_record: &DamagedRecord, gap: usize) -> usize {
+        let last = self.fill.len() - 1;
+        if gap == last {
+            return usize::from(self.matches(damaged_record));
+        }
+
+        let spare = self.fill[last];
+        let mut count = 0;
+        for moved in 0..=spare {
+            self.fill[gap] += moved;
+            self.fill[last] -= moved;
+            count += self.count_matching(damaged_record, gap + 1);
+            self.fill[gap] -= moved;
+            self.fill[last] += moved;
+        }
+        count
+    }
+}
+
+impl DamagedRecord {
+    fn arrangements(&self) -> usize {
+        RecordCandidate::new(self).map_or(0, |mut candidate| candidate.count_matching(self, 0))
+    }
 }
 
 fn parse_record(input: &str) -> IResult<&str, DamagedRecord> {
@@ -92,16 +128,19 @@ fn parse_record(input: &str) -> IResult<&str, DamagedRecord> {
 
 #[tracing::instrument]
 pub fn process_line(line: &str) -> miette::Result<usize, AocError> {
-    let (_, damaged_record) = dbg!(parse_record(line).unwrap());
-    let record = RecordCandidate::new(&damaged_record);
-    assert_eq!(record.len(), damaged_record.state.len());
+    let damaged_record = parse_complete(line, parse_record)?;
 
-    Ok(0)
+    Ok(damaged_record.arrangements())
 }
 
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<usize, AocError> {
-    todo!("Part 1");
+    let damaged_records = parse_complete(input, lines(parse_record))?;
+
+    Ok(damaged_records
+        .iter()
+        .map(DamagedRecord::arrangements)
+        .sum())
 }
 
 #[cfg(test)]
@@ -1,13 +1,124 @@
-use crate::custom_error::AocError;
+use aoc_common::{
+    custom_error::AocError,
+    parsing::{lines, parse_complete},
+};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{self, one_of, space1},
+    multi::{many1, separated_list1},
+    sequence::separated_pair,
+    IResult,
+};
+
+const UNFOLD_TIMES: usize = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Operational,
+    Damaged,
+}
+
+impl State {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Self::Operational),
+            '#' => Some(Self::Damaged),
+            '?' => None,
+            _ => panic!("Unrecognized character!"),
+        }
+    }
+
+    fn matches(&self, s: &Option<State>) -> bool {
+        match s {
+            None => true,
+            Some(x) => self == x,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct DamagedRecord {
+    state: Vec<Option<State>>,
+    grouping: Vec<usize>,
+}
+
+impl DamagedRecord {
+    // Repeat the record `times` times, the copies of the springs being joined by an unknown one
+    fn unfold(&self, times: usize) -> Self {
+        let state = vec![self.state.as_slice(); times].join(&None);
+        let grouping = self.grouping.repeat(times);
+
+        Self { state, grouping }
+    }
+
+    // Whether the `length` springs from `start` can all be damaged, with an operational one (or the end) right after
+    fn fits_group(&self, start: usize, length: usize) -> bool {
+        let end = start + length;
+        end <= self.state.len()
+            && self.state[start..end]
+                .iter()
+                .all(|s| State::Damaged.matches(s))
+            && self.state.get(end) != Some(&Some(State::Damaged))
+    }
+
+    fn arrangements(&self) -> usize {
+        let n = self.state.len();
+        let m = self.grouping.len();
+
+        // `counts[i][j]` is the number of arrangements of the springs from `i` onwards with the groups from `j` onwards
+        let mut counts = vec![vec![0; m + 1]; n + 2];
+        counts[n][m] = 1;
+        counts[n + 1][m] = 1;
+
+        for i in (0..n).rev() {
+            for j in (0..=m).rev() {
+                // Spring `i` is operational
+                if State::Operational.matches(&self.state[i]) {
+                    counts[i][j] += counts[i + 1][j];
+                }
+                // Group `j` starts at spring `i`, skipping the operational spring that follows it
+                if j < m && self.fits_group(i, self.grouping[j]) {
+                    counts[i][j] += counts[i + self.grouping[j] + 1][j + 1];
+                }
+            }
+        }
+
+        counts[0][0]
+    }
+}
+
+fn parse_record(input: &str) -> IResult<&str, DamagedRecord> {
+    separated_pair(
+        many1(one_of(".#?")),
+        space1,
+        separated_list1(tag(","), complete::u32),
+    )(input)
+    .map(|(input, (springs_chars, grouping))| {
+        (
+            input,
+            DamagedRecord {
+                state: springs_chars.into_iter().map(State::from_char).collect(),
+                grouping: grouping.into_iter().map(|x| x as usize).collect(),
+            },
+        )
+    })
+}
 
 #[tracing::instrument]
-pub fn process_line(line: &str) -> miette::Result<u32, AocError> {
-    todo!("Part 2");
+pub fn process_line(line: &str) -> miette::Result<usize, AocError> {
+    let damaged_record = parse_complete(line, parse_record)?;
+
+    Ok(damaged_record.unfold(UNFOLD_TIMES).arrangements())
 }
 
 #[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<u32, AocError> {
-    todo!("Part 2");
+pub fn process(input: &str) -> miette::Result<usize, AocError> {
+    let damaged_records = parse_complete(input, lines(parse_record))?;
+
+    Ok(damaged_records
+        .iter()
+        .map(|damaged_record| damaged_record.unfold(UNFOLD_TIMES).arrangements())
+        .sum())
 }
 
 #[cfg(test)]
@@ -16,18 +127,26 @@ mod tests {
     use rstest::rstest;
 
     #[rstest]
-    #[case("", 0)]
-    fn test_lines(#[case] line: &str, #[case] expected: u32) -> miette::Result<()> {
-        todo!("haven't built test yet");
+    #[case("???.### 1,1,3", 1)]
+    #[case(".??..??...?##. 1,1,3", 16384)]
+    #[case("?#?#?#?#?#?#?#? 1,3,1,6", 1)]
+    #[case("????.#...#... 4,1,1", 16)]
+    #[case("????.######..#####. 1,6,5", 2500)]
+    #[case("?###???????? 3,2,1", 506250)]
+    fn test_lines(#[case] line: &str, #[case] expected: usize) -> miette::Result<()> {
         assert_eq!(expected, process_line(line)?);
         Ok(())
     }
 
     #[test]
     fn test_process() -> miette::Result<()> {
-        todo!("haven't built test yet");
-        let input = "";
-        assert_eq!(0, process(input)?);
+        let input = "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+        assert_eq!(525152, process(input)?);
         Ok(())
     }
 }
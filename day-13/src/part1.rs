@@ -0,0 +1,141 @@
+use aoc_common::{
+    custom_error::AocError,
+    grid::{Grid, UnrecognizedChar},
+    parsing::{grid, parse_complete},
+};
+use ndarray::ArrayView2;
+use nom::{
+    character::complete::line_ending, multi::separated_list1, sequence::pair, IResult, Parser,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Terrain {
+    Ash,  // .
+    Rock, // #
+}
+
+impl TryFrom<char> for Terrain {
+    type Error = UnrecognizedChar;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(Self::Ash),
+            '#' => Ok(Self::Rock),
+            _ => Err(UnrecognizedChar(c)),
+        }
+    }
+}
+
+// Line of reflection, given by the number of columns to its left or rows above it
+#[derive(Debug, PartialEq, Eq)]
+enum Reflection {
+    Vertical(usize),
+    Horizontal(usize),
+}
+
+impl Reflection {
+    fn summary(&self) -> usize {
+        match self {
+            Reflection::Vertical(columns) => *columns,
+            Reflection::Horizontal(rows) => 100 * rows,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Pattern {
+    terrain: Grid<Terrain>,
+}
+
+impl Pattern {
+    fn reflection(&self) -> Option<Reflection> {
+        mirror_row(self.terrain.t())
+            .map(Reflection::Vertical)
+            .or_else(|| mirror_row(self.terrain.view()).map(Reflection::Horizontal))
+    }
+}
+
+// Number of rows above the horizontal line reflecting the view perfectly, if any
+fn mirror_row(view: ArrayView2<Terrain>) -> Option<usize> {
+    (1..view.nrows()).find(|&row| {
+        // Pairs of rows at the same distance of the line, until one side runs out
+        (0..row.min(view.nrows() - row))
+            .all(|offset| view.row(row - 1 - offset) == view.row(row + offset))
+    })
+}
+
+fn parse_patterns(input: &str) -> IResult<&str, Vec<Pattern>> {
+    separated_list1(
+        pair(line_ending, line_ending),
+        grid.map(|terrain| Pattern { terrain }),
+    )(input)
+}
+
+#[tracing::instrument]
+pub fn process(input: &str) -> miette::Result<usize, AocError> {
+    let patterns = parse_complete(input, parse_patterns)?;
+
+    patterns
+        .iter()
+        .map(|pattern| {
+            pattern
+                .reflection()
+                .map(|reflection| reflection.summary())
+                .ok_or_else(|| AocError::NoSolution("pattern without reflection".to_owned()))
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(
+        "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.##..##.",
+        Reflection::Vertical(5)
+    )]
+    #[case(
+        "#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#",
+        Reflection::Horizontal(4)
+    )]
+    fn test_patterns(#[case] pattern: &str, #[case] expected: Reflection) -> miette::Result<()> {
+        let terrain = parse_complete(pattern, grid)?;
+        assert_eq!(Some(expected), Pattern { terrain }.reflection());
+        Ok(())
+    }
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        let input = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.##..##.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+        assert_eq!(405, process(input)?);
+        Ok(())
+    }
+}
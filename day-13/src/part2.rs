@@ -0,0 +1,157 @@
+use aoc_common::{
+    custom_error::AocError,
+    grid::{Grid, UnrecognizedChar},
+    parsing::{grid, parse_complete},
+};
+use ndarray::ArrayView2;
+use nom::{
+    character::complete::line_ending, multi::separated_list1, sequence::pair, IResult, Parser,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Terrain {
+    Ash,  // .
+    Rock, // #
+}
+
+impl TryFrom<char> for Terrain {
+    type Error = UnrecognizedChar;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(Self::Ash),
+            '#' => Ok(Self::Rock),
+            _ => Err(UnrecognizedChar(c)),
+        }
+    }
+}
+
+// Line of reflection, given by the number of columns to its left or rows above it
+#[derive(Debug, PartialEq, Eq)]
+enum Reflection {
+    Vertical(usize),
+    Horizontal(usize),
+}
+
+impl Reflection {
+    fn summary(&self) -> usize {
+        match self {
+            Reflection::Vertical(columns) => *columns,
+            Reflection::Horizontal(rows) => 100 * rows,
+        }
+    }
+}
+
+// Every pattern has exactly one smudge, which must lie on the reflection
+const SMUDGES: usize = 1;
+
+#[derive(Debug)]
+struct Pattern {
+    terrain: Grid<Terrain>,
+}
+
+impl Pattern {
+    // Reflection found once `smudges` tiles are fixed
+    fn reflection(&self, smudges: usize) -> Option<Reflection> {
+        mirror_row(self.terrain.t(), smudges)
+            .map(Reflection::Vertical)
+            .or_else(|| mirror_row(self.terrain.view(), smudges).map(Reflection::Horizontal))
+    }
+}
+
+// Number of rows above the horizontal line reflecting the view with exactly `smudges` mismatching tiles, if any
+fn mirror_row(view: ArrayView2<Terrain>, smudges: usize) -> Option<usize> {
+    (1..view.nrows()).find(|&row| {
+        // Pairs of rows at the same distance of the line, until one side runs out
+        let mismatches: usize = (0..row.min(view.nrows() - row))
+            .map(|offset| {
+                view.row(row - 1 - offset)
+                    .iter()
+                    .zip(view.row(row + offset))
+                    .filter(|(a, b)| a != b)
+                    .count()
+            })
+            .sum();
+        mismatches == smudges
+    })
+}
+
+fn parse_patterns(input: &str) -> IResult<&str, Vec<Pattern>> {
+    separated_list1(
+        pair(line_ending, line_ending),
+        grid.map(|terrain| Pattern { terrain }),
+    )(input)
+}
+
+#[tracing::instrument]
+pub fn process(input: &str) -> miette::Result<usize, AocError> {
+    let patterns = parse_complete(input, parse_patterns)?;
+
+    patterns
+        .iter()
+        .map(|pattern| {
+            pattern
+                .reflection(SMUDGES)
+                .map(|reflection| reflection.summary())
+                .ok_or_else(|| {
+                    AocError::NoSolution(
+                        "pattern without reflection after fixing its smudge".to_owned(),
+                    )
+                })
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(
+        "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.##..##.",
+        Reflection::Horizontal(3)
+    )]
+    #[case(
+        "#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#",
+        Reflection::Horizontal(1)
+    )]
+    fn test_patterns(#[case] pattern: &str, #[case] expected: Reflection) -> miette::Result<()> {
+        let terrain = parse_complete(pattern, grid)?;
+        assert_eq!(Some(expected), Pattern { terrain }.reflection(SMUDGES));
+        Ok(())
+    }
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        let input = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.##..##.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+        assert_eq!(400, process(input)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,232 @@
+use aoc_common::{
+    custom_error::AocError,
+    grid::{Direction, Grid, UnrecognizedChar},
+    parsing::{grid, parse_complete},
+};
+use ndarray::{s, ArrayViewMut1, Axis};
+use nom::{IResult, Parser};
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+
+const SPIN_CYCLES: usize = 1_000_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Rock {
+    Round, // O
+    Cube,  // #
+    Empty, // .
+}
+
+impl TryFrom<char> for Rock {
+    type Error = UnrecognizedChar;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'O' => Ok(Self::Round),
+            '#' => Ok(Self::Cube),
+            '.' => Ok(Self::Empty),
+            _ => Err(UnrecognizedChar(c)),
+        }
+    }
+}
+
+impl From<Rock> for char {
+    fn from(rock: Rock) -> Self {
+        match rock {
+            Rock::Round => 'O',
+            Rock::Cube => '#',
+            Rock::Empty => '.',
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Platform {
+    rocks: Grid<Rock>,
+}
+
+impl Platform {
+    // Tilt the whole platform so that every rounded rock rolls as far as possible
+    fn tilt(&mut self, direction: Direction) {
+        // Lanes in which rocks roll towards their first element
+        match direction {
+            Direction::North => self.rocks.columns_mut().into_iter().for_each(roll),
+            Direction::West => self.rocks.rows_mut().into_iter().for_each(roll),
+            Direction::South => self
+                .rocks
+                .columns_mut()
+                .into_iter()
+                .for_each(|mut lane| roll(lane.slice_mut(s![..;-1]))),
+            Direction::East => self
+                .rocks
+                .rows_mut()
+                .into_iter()
+                .for_each(|mut lane| roll(lane.slice_mut(s![..;-1]))),
+        }
+    }
+
+    // One spin cycle tilts the platform north, then west, then south, then east
+    fn spin(&mut self) {
+        for direction in [
+            Direction::North,
+            Direction::West,
+            Direction::South,
+            Direction::East,
+        ] {
+            self.tilt(direction);
+        }
+    }
+
+    // Platform after some number of spin cycles, skipping ahead once the states start repeating
+    fn spin_many(mut self, cycles: usize) -> Self {
+        let mut seen = HashMap::new();
+        let mut history = Vec::new();
+
+        for cycle in 0..cycles {
+            if let Some(&start) = seen.get(&self) {
+                let period = cycle - start;
+                return history.swap_remove(start + (cycles - start) % period);
+            }
+            seen.insert(self.clone(), cycle);
+            history.push(self.clone());
+            self.spin();
+        }
+
+        self
+    }
+
+    // Total load on the north support beams, each rounded rock weighing its distance to the south edge
+    fn north_load(&self) -> usize {
+        let nrows = self.rocks.nrows();
+        self.rocks
+            .axis_iter(Axis(0))
+            .enumerate()
+            .map(|(row, rocks)| (nrows - row) * rocks.iter().filter(|&&r| r == Rock::Round).count())
+            .sum()
+    }
+}
+
+// Roll every rounded rock of the lane towards its start, until blocked by a cube rock or another rounded rock
+fn roll(mut lane: ArrayViewMut1<Rock>) {
+    let mut free = 0;
+    for idx in 0..lane.len() {
+        match lane[idx] {
+            Rock::Cube => free = idx + 1,
+            Rock::Round => {
+                lane.swap(free, idx);
+                free += 1;
+            }
+            Rock::Empty => {}
+        }
+    }
+}
+
+impl Display for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (row, rocks) in self.rocks.axis_iter(Axis(0)).enumerate() {
+            if row > 0 {
+                writeln!(f)?;
+            }
+            let line: String = rocks.iter().map(|&rock| char::from(rock)).collect();
+            write!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_platform(input: &str) -> IResult<&str, Platform> {
+    grid.map(|rocks| Platform { rocks }).parse(input)
+}
+
+#[tracing::instrument]
+pub fn process(input: &str) -> miette::Result<usize, AocError> {
+    let platform = parse_complete(input, parse_platform)?;
+
+    Ok(platform.spin_many(SPIN_CYCLES).north_load())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const INPUT: &str = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+    #[test]
+    fn test_display() -> miette::Result<()> {
+        let platform = parse_complete(INPUT, parse_platform)?;
+        assert_eq!(INPUT, platform.to_string());
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(
+        1,
+        ".....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#...."
+    )]
+    #[case(
+        2,
+        ".....#....
+....#...O#
+.....##...
+..O#......
+.....OOO#.
+.O#...O#.#
+....O#...O
+.......OOO
+#..OO###..
+#.OOO#...O"
+    )]
+    #[case(
+        3,
+        ".....#....
+....#...O#
+.....##...
+..O#......
+.....OOO#.
+.O#...O#.#
+....O#...O
+.......OOO
+#...O###.O
+#.OOO#...O"
+    )]
+    fn test_spin(#[case] cycles: usize, #[case] expected: &str) -> miette::Result<()> {
+        let mut platform = parse_complete(INPUT, parse_platform)?;
+        for _ in 0..cycles {
+            platform.spin();
+        }
+        assert_eq!(expected, platform.to_string());
+        // Skipping ahead must give the same state as spinning one cycle after the other
+        assert_eq!(
+            expected,
+            parse_complete(INPUT, parse_platform)?
+                .spin_many(cycles)
+                .to_string()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        assert_eq!(64, process(INPUT)?);
+        Ok(())
+    }
+}
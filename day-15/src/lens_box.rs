@@ -0,0 +1,73 @@
+/// Box of entries kept in insertion order
+///
+/// Inserting an entry whose key is already present replaces its value in place,
+/// while removing one keeps the other entries in the same order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LensBox<K, V> {
+    entries: Vec<(K, V)>,
+}
+
+impl<K, V> Default for LensBox<K, V> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl<K: PartialEq, V> LensBox<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, key: K, value: V) {
+        match self.entries.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, v)) => *v = value,
+            None => self.entries.push((key, value)),
+        }
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let idx = self.entries.iter().position(|(k, _)| k == key)?;
+        Some(self.entries.remove(idx).1)
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.entries.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.entries.iter().map(|(k, v)| (k, v))
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insertion_order() {
+        let mut lens_box = LensBox::new();
+        lens_box.insert("ot", 9);
+        lens_box.insert("ab", 5);
+        lens_box.insert("pc", 6);
+        // Replacing keeps the position, removing keeps the order of the rest
+        lens_box.insert("ot", 7);
+        assert_eq!(Some(5), lens_box.remove(&"ab"));
+        assert_eq!(None, lens_box.remove(&"ab"));
+        assert_eq!(
+            vec![(&"ot", &7), (&"pc", &6)],
+            lens_box.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(Some(&6), lens_box.get(&"pc"));
+        assert_eq!(2, lens_box.len());
+    }
+}
@@ -0,0 +1,19 @@
+use aoc_common::solution::{Answer, Solution};
+
+pub mod lens_box;
+pub mod part1;
+pub mod part2;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    fn part1(input: &str) -> miette::Result<Answer> {
+        Ok(part1::process(input)?.into())
+    }
+
+    fn part2(input: &str) -> miette::Result<Answer> {
+        Ok(part2::process(input)?.into())
+    }
+}
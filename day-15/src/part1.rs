@@ -0,0 +1,50 @@
+use aoc_common::{custom_error::AocError, parsing::parse_complete};
+use nom::{bytes::complete::is_not, bytes::complete::tag, multi::separated_list1, IResult};
+
+// Holiday ASCII String Helper algorithm
+fn hash(step: &str) -> u8 {
+    step.bytes().fold(0, |current, byte| {
+        current.wrapping_add(byte).wrapping_mul(17)
+    })
+}
+
+fn parse_steps(input: &str) -> IResult<&str, Vec<&str>> {
+    separated_list1(tag(","), is_not(",\r\n"))(input)
+}
+
+#[tracing::instrument]
+pub fn process(input: &str) -> miette::Result<u32, AocError> {
+    let steps = parse_complete(input, parse_steps)?;
+
+    Ok(steps.into_iter().map(|step| hash(step) as u32).sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("HASH", 52)]
+    #[case("rn=1", 30)]
+    #[case("cm-", 253)]
+    #[case("qp=3", 97)]
+    #[case("cm=2", 47)]
+    #[case("qp-", 14)]
+    #[case("pc=4", 180)]
+    #[case("ot=9", 9)]
+    #[case("ab=5", 197)]
+    #[case("pc-", 48)]
+    #[case("pc=6", 214)]
+    #[case("ot=7", 231)]
+    fn test_hash(#[case] step: &str, #[case] expected: u8) {
+        assert_eq!(expected, hash(step));
+    }
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7\n";
+        assert_eq!(1320, process(input)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+use crate::lens_box::LensBox;
+use aoc_common::{custom_error::AocError, parsing::parse_complete};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{self, alpha1},
+    multi::separated_list1,
+    sequence::preceded,
+    IResult, Parser,
+};
+use std::fmt::{self, Display};
+
+const BOX_COUNT: usize = 256;
+
+// Holiday ASCII String Helper algorithm
+fn hash(step: &str) -> u8 {
+    step.bytes().fold(0, |current, byte| {
+        current.wrapping_add(byte).wrapping_mul(17)
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operation {
+    Remove,
+    Insert(u8),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Step<'a> {
+    label: &'a str,
+    operation: Operation,
+}
+
+// Holiday ASCII String Helper Manual Arrangement Procedure
+#[derive(Debug)]
+struct Hashmap<'a> {
+    boxes: Vec<LensBox<&'a str, u8>>,
+}
+
+impl<'a> Hashmap<'a> {
+    fn new() -> Self {
+        Self {
+            boxes: vec![LensBox::new(); BOX_COUNT],
+        }
+    }
+
+    fn apply(&mut self, step: &Step<'a>) {
+        let lens_box = &mut self.boxes[hash(step.label) as usize];
+        match step.operation {
+            Operation::Remove => {
+                lens_box.remove(&step.label);
+            }
+            Operation::Insert(focal_length) => lens_box.insert(step.label, focal_length),
+        }
+    }
+
+    fn focusing_power(&self) -> usize {
+        self.boxes
+            .iter()
+            .enumerate()
+            .flat_map(|(box_idx, lens_box)| {
+                lens_box
+                    .iter()
+                    .enumerate()
+                    .map(move |(slot, (_, &focal_length))| {
+                        (box_idx + 1) * (slot + 1) * focal_length as usize
+                    })
+            })
+            .sum()
+    }
+}
+
+// Non-empty boxes, one per line, as shown in the puzzle: `Box 3: [pc 4] [ot 9]`
+impl Display for Hashmap<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+        for (box_idx, lens_box) in self.boxes.iter().enumerate() {
+            if lens_box.is_empty() {
+                continue;
+            }
+            if !first {
+                writeln!(f)?;
+            }
+            first = false;
+            write!(f, "Box {box_idx}:")?;
+            for (label, focal_length) in lens_box.iter() {
+                write!(f, " [{label} {focal_length}]")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn parse_step(input: &str) -> IResult<&str, Step<'_>> {
+    let operation = alt((
+        tag("-").map(|_| Operation::Remove),
+        preceded(tag("="), complete::u8).map(Operation::Insert),
+    ));
+    alpha1
+        .and(operation)
+        .map(|(label, operation)| Step { label, operation })
+        .parse(input)
+}
+
+fn parse_steps(input: &str) -> IResult<&str, Vec<Step<'_>>> {
+    separated_list1(tag(","), parse_step)(input)
+}
+
+#[tracing::instrument]
+pub fn process(input: &str) -> miette::Result<usize, AocError> {
+    let steps = parse_complete(input, parse_steps)?;
+
+    let mut hashmap = Hashmap::new();
+    for step in &steps {
+        hashmap.apply(step);
+    }
+
+    Ok(hashmap.focusing_power())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7\n";
+
+    #[test]
+    fn test_steps() -> miette::Result<()> {
+        let expected_states = [
+            "Box 0: [rn 1]",
+            "Box 0: [rn 1]",
+            "Box 0: [rn 1]\nBox 1: [qp 3]",
+            "Box 0: [rn 1] [cm 2]\nBox 1: [qp 3]",
+            "Box 0: [rn 1] [cm 2]",
+            "Box 0: [rn 1] [cm 2]\nBox 3: [pc 4]",
+            "Box 0: [rn 1] [cm 2]\nBox 3: [pc 4] [ot 9]",
+            "Box 0: [rn 1] [cm 2]\nBox 3: [pc 4] [ot 9] [ab 5]",
+            "Box 0: [rn 1] [cm 2]\nBox 3: [ot 9] [ab 5]",
+            "Box 0: [rn 1] [cm 2]\nBox 3: [ot 9] [ab 5] [pc 6]",
+            "Box 0: [rn 1] [cm 2]\nBox 3: [ot 7] [ab 5] [pc 6]",
+        ];
+
+        let steps = parse_complete(INPUT, parse_steps)?;
+        assert_eq!(expected_states.len(), steps.len());
+
+        let mut hashmap = Hashmap::new();
+        for (step, expected) in steps.iter().zip(expected_states) {
+            hashmap.apply(step);
+            assert_eq!(expected, hashmap.to_string());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        assert_eq!(145, process(INPUT)?);
+        Ok(())
+    }
+}
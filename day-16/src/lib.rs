@@ -0,0 +1,20 @@
+use aoc_common::solution::{Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    // Part 2 runs on rayon's thread pool
+    const WASM_COMPATIBLE: bool = false;
+
+    fn part1(input: &str) -> miette::Result<Answer> {
+        Ok(part1::process(input)?.into())
+    }
+
+    fn part2(input: &str) -> miette::Result<Answer> {
+        Ok(part2::process(input)?.into())
+    }
+}
@@ -0,0 +1,161 @@
+use aoc_common::{
+    custom_error::AocError,
+    grid::{Direction, Grid, UnrecognizedChar},
+    parsing::{grid, parse_complete},
+};
+use nom::{IResult, Parser};
+use rayon::prelude::*;
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Empty,              // .
+    MirrorSlash,        // /
+    MirrorBackslash,    // \
+    SplitterVertical,   // |
+    SplitterHorizontal, // -
+}
+
+impl TryFrom<char> for Tile {
+    type Error = UnrecognizedChar;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(Self::Empty),
+            '/' => Ok(Self::MirrorSlash),
+            '\\' => Ok(Self::MirrorBackslash),
+            '|' => Ok(Self::SplitterVertical),
+            '-' => Ok(Self::SplitterHorizontal),
+            _ => Err(UnrecognizedChar(c)),
+        }
+    }
+}
+
+impl Tile {
+    // Directions in which a beam arriving with `direction` leaves the tile
+    fn outgoing(&self, direction: Direction) -> Vec<Direction> {
+        match (self, direction) {
+            (Self::MirrorSlash, Direction::East | Direction::West) => vec![direction.turn_left()],
+            (Self::MirrorSlash, Direction::North | Direction::South) => {
+                vec![direction.turn_right()]
+            }
+            (Self::MirrorBackslash, Direction::East | Direction::West) => {
+                vec![direction.turn_right()]
+            }
+            (Self::MirrorBackslash, Direction::North | Direction::South) => {
+                vec![direction.turn_left()]
+            }
+            (Self::SplitterVertical, Direction::East | Direction::West) => {
+                vec![Direction::North, Direction::South]
+            }
+            (Self::SplitterHorizontal, Direction::North | Direction::South) => {
+                vec![Direction::East, Direction::West]
+            }
+            _ => vec![direction],
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Contraption {
+    tiles: Grid<Tile>,
+}
+
+impl Contraption {
+    // Number of tiles crossed by at least one beam, for a beam entering at `start` with `direction`
+    fn energized(&self, start: [usize; 2], direction: Direction) -> usize {
+        let mut visited = HashSet::new();
+        let mut beams = vec![(start, direction)];
+
+        while let Some((idx, direction)) = beams.pop() {
+            // Beams following the path of a previous one won't energize anything new
+            if !visited.insert((idx, direction)) {
+                continue;
+            }
+            beams.extend(
+                self.tiles[idx]
+                    .outgoing(direction)
+                    .into_iter()
+                    .filter_map(|next| self.tiles.step(idx, next).map(|next_idx| (next_idx, next))),
+            );
+        }
+
+        visited
+            .into_iter()
+            .map(|(idx, _)| idx)
+            .collect::<HashSet<_>>()
+            .len()
+    }
+}
+
+impl Contraption {
+    // Every way for a beam to enter the contraption, from any edge tile towards the inside
+    fn entry_points(&self) -> Vec<([usize; 2], Direction)> {
+        let (nrows, ncols) = self.tiles.dim();
+        let rows = (0..nrows).flat_map(|row| {
+            [
+                ([row, 0], Direction::East),
+                ([row, ncols - 1], Direction::West),
+            ]
+        });
+        let columns = (0..ncols).flat_map(|col| {
+            [
+                ([0, col], Direction::South),
+                ([nrows - 1, col], Direction::North),
+            ]
+        });
+        rows.chain(columns).collect()
+    }
+}
+
+fn parse_contraption(input: &str) -> IResult<&str, Contraption> {
+    grid.map(|tiles| Contraption { tiles }).parse(input)
+}
+
+#[tracing::instrument]
+pub fn process(input: &str) -> miette::Result<usize, AocError> {
+    let contraption = parse_complete(input, parse_contraption)?;
+
+    Ok(contraption
+        .entry_points()
+        .into_par_iter()
+        .map(|(start, direction)| contraption.energized(start, direction))
+        .max()
+        .unwrap_or(0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const INPUT: &str = r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....";
+
+    #[rstest]
+    #[case([0, 0], Direction::East, 46)]
+    #[case([0, 3], Direction::South, 51)]
+    fn test_energized(
+        #[case] start: [usize; 2],
+        #[case] direction: Direction,
+        #[case] expected: usize,
+    ) -> miette::Result<()> {
+        let contraption = parse_complete(INPUT, parse_contraption)?;
+        assert_eq!(expected, contraption.energized(start, direction));
+        Ok(())
+    }
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        assert_eq!(51, process(INPUT)?);
+        Ok(())
+    }
+}
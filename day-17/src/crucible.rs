@@ -0,0 +1,96 @@
+use aoc_common::{
+    grid::{Direction, Grid, UnrecognizedChar},
+    parsing::grid,
+};
+use nom::IResult;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+/// Heat lost when a crucible enters a city block
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeatLoss(pub u32);
+
+impl TryFrom<char> for HeatLoss {
+    type Error = UnrecognizedChar;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        c.to_digit(10).map(HeatLoss).ok_or(UnrecognizedChar(c))
+    }
+}
+
+pub fn parse_city(input: &str) -> IResult<&str, Grid<HeatLoss>> {
+    grid(input)
+}
+
+// The crucible can only turn, so all that matters about its past is the axis it last moved along
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Axis {
+    Horizontal,
+    Vertical,
+}
+
+impl Axis {
+    fn turns(&self) -> [Direction; 2] {
+        match self {
+            Axis::Horizontal => [Direction::North, Direction::South],
+            Axis::Vertical => [Direction::East, Direction::West],
+        }
+    }
+}
+
+impl From<Direction> for Axis {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::East | Direction::West => Axis::Horizontal,
+            Direction::North | Direction::South => Axis::Vertical,
+        }
+    }
+}
+
+/// Least heat loss from the top-left to the bottom-right block, for a crucible that must move between
+/// `min_run` and `max_run` blocks in a straight line before turning (Dijkstra over block and last axis)
+pub fn min_heat_loss(city: &Grid<HeatLoss>, min_run: usize, max_run: usize) -> Option<u32> {
+    let target = [city.nrows() - 1, city.ncols() - 1];
+
+    let mut best = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    for axis in [Axis::Horizontal, Axis::Vertical] {
+        best.insert(([0, 0], axis), 0);
+        queue.push(Reverse((0, [0, 0], axis)));
+    }
+
+    while let Some(Reverse((loss, idx, axis))) = queue.pop() {
+        if idx == target {
+            return Some(loss);
+        }
+        // Outdated entry, the state has been reached with less heat loss since
+        if best.get(&(idx, axis)).is_some_and(|&b| b < loss) {
+            continue;
+        }
+
+        for direction in axis.turns() {
+            let mut next_idx = idx;
+            let mut next_loss = loss;
+            for run in 1..=max_run {
+                let Some(next) = city.step(next_idx, direction) else {
+                    break;
+                };
+                next_idx = next;
+                next_loss += city[next_idx].0;
+
+                // Too early to turn or stop
+                if run < min_run {
+                    continue;
+                }
+                let next_state = (next_idx, Axis::from(direction));
+                if best.get(&next_state).is_some_and(|&b| b <= next_loss) {
+                    continue;
+                }
+                best.insert(next_state, next_loss);
+                queue.push(Reverse((next_loss, next_idx, next_state.1)));
+            }
+        }
+    }
+
+    None
+}
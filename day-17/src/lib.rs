@@ -0,0 +1,19 @@
+use aoc_common::solution::{Answer, Solution};
+
+pub mod crucible;
+pub mod part1;
+pub mod part2;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    fn part1(input: &str) -> miette::Result<Answer> {
+        Ok(part1::process(input)?.into())
+    }
+
+    fn part2(input: &str) -> miette::Result<Answer> {
+        Ok(part2::process(input)?.into())
+    }
+}
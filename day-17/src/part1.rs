@@ -0,0 +1,38 @@
+use crate::crucible::{min_heat_loss, parse_city};
+use aoc_common::{custom_error::AocError, parsing::parse_complete};
+
+// Crucibles move at most three blocks in a straight line
+const MIN_RUN: usize = 1;
+const MAX_RUN: usize = 3;
+
+#[tracing::instrument]
+pub fn process(input: &str) -> miette::Result<u32, AocError> {
+    let city = parse_complete(input, parse_city)?;
+
+    min_heat_loss(&city, MIN_RUN, MAX_RUN)
+        .ok_or_else(|| AocError::NoSolution("the crucible can not reach the factory".to_owned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        let input = "2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533";
+        assert_eq!(102, process(input)?);
+        Ok(())
+    }
+}
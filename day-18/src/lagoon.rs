@@ -0,0 +1,94 @@
+use aoc_common::{
+    grid::Direction,
+    polygon::{double_area, interior_points},
+};
+use nom::{
+    bytes::complete::{tag, take_while_m_n},
+    character::complete::{self, one_of, space1},
+    combinator::map_res,
+    sequence::{delimited, pair, separated_pair},
+    IResult, Parser,
+};
+
+/// Trench dug from the current position, `distance` meters in `direction`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    pub direction: Direction,
+    pub distance: i64,
+}
+
+/// Line of the dig plan, with the instruction hidden in its hexadecimal color code
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlanLine {
+    pub instruction: Instruction,
+    pub color_instruction: Instruction,
+}
+
+fn parse_direction(c: char) -> Direction {
+    match c {
+        'U' | '3' => Direction::North,
+        'D' | '1' => Direction::South,
+        'L' | '2' => Direction::West,
+        'R' | '0' => Direction::East,
+        _ => unreachable!("only recognized characters are parsed"),
+    }
+}
+
+// `#70c710`: the first five digits are the distance in hexadecimal, the last one the direction
+fn parse_color_instruction(input: &str) -> IResult<&str, Instruction> {
+    pair(
+        map_res(
+            take_while_m_n(5, 5, |c: char| c.is_ascii_hexdigit()),
+            |digits| i64::from_str_radix(digits, 16),
+        ),
+        one_of("0123").map(parse_direction),
+    )
+    .map(|(distance, direction)| Instruction {
+        direction,
+        distance,
+    })
+    .parse(input)
+}
+
+pub fn parse_plan_line(input: &str) -> IResult<&str, PlanLine> {
+    separated_pair(
+        separated_pair(
+            one_of("UDLR").map(parse_direction),
+            space1,
+            complete::u32.map(i64::from),
+        ),
+        space1,
+        delimited(tag("(#"), parse_color_instruction, tag(")")),
+    )
+    .map(|((direction, distance), color_instruction)| PlanLine {
+        instruction: Instruction {
+            direction,
+            distance,
+        },
+        color_instruction,
+    })
+    .parse(input)
+}
+
+/// Cubic meters of lava held by the lagoon dug along the instructions, trench included
+pub fn lagoon_volume(instructions: impl IntoIterator<Item = Instruction>) -> i64 {
+    let mut vertices = vec![];
+    let mut boundary = 0;
+    let [mut y, mut x] = [0, 0];
+    for Instruction {
+        direction,
+        distance,
+    } in instructions
+    {
+        match direction {
+            Direction::North => y -= distance,
+            Direction::South => y += distance,
+            Direction::West => x -= distance,
+            Direction::East => x += distance,
+        }
+        vertices.push([y, x]);
+        boundary += distance;
+    }
+
+    interior_points(double_area(&vertices), boundary) + boundary
+}
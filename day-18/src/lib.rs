@@ -0,0 +1,19 @@
+use aoc_common::solution::{Answer, Solution};
+
+pub mod lagoon;
+pub mod part1;
+pub mod part2;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    fn part1(input: &str) -> miette::Result<Answer> {
+        Ok(part1::process(input)?.into())
+    }
+
+    fn part2(input: &str) -> miette::Result<Answer> {
+        Ok(part2::process(input)?.into())
+    }
+}
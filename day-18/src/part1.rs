@@ -0,0 +1,62 @@
+use crate::lagoon::{lagoon_volume, parse_plan_line};
+use aoc_common::{
+    custom_error::AocError,
+    parsing::{lines, parse_complete},
+};
+
+#[tracing::instrument]
+pub fn process(input: &str) -> miette::Result<i64, AocError> {
+    let plan = parse_complete(input, lines(parse_plan_line))?;
+
+    Ok(lagoon_volume(plan.iter().map(|line| line.instruction)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lagoon::Instruction;
+    use aoc_common::grid::Direction;
+    use rstest::rstest;
+
+    const INPUT: &str = "R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)";
+
+    #[rstest]
+    #[case("R 6 (#70c710)", Direction::East, 6)]
+    #[case("D 5 (#0dc571)", Direction::South, 5)]
+    #[case("L 2 (#5713f0)", Direction::West, 2)]
+    #[case("U 3 (#a77fa3)", Direction::North, 3)]
+    fn test_lines(
+        #[case] line: &str,
+        #[case] direction: Direction,
+        #[case] distance: i64,
+    ) -> miette::Result<()> {
+        let plan_line = parse_complete(line, parse_plan_line)?;
+        assert_eq!(
+            Instruction {
+                direction,
+                distance
+            },
+            plan_line.instruction
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        assert_eq!(62, process(INPUT)?);
+        Ok(())
+    }
+}
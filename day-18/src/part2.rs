@@ -0,0 +1,71 @@
+use crate::lagoon::{lagoon_volume, parse_plan_line};
+use aoc_common::{
+    custom_error::AocError,
+    parsing::{lines, parse_complete},
+};
+
+#[tracing::instrument]
+pub fn process(input: &str) -> miette::Result<i64, AocError> {
+    let plan = parse_complete(input, lines(parse_plan_line))?;
+
+    Ok(lagoon_volume(
+        plan.iter().map(|line| line.color_instruction),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lagoon::Instruction;
+    use aoc_common::grid::Direction;
+    use rstest::rstest;
+
+    const INPUT: &str = "R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)";
+
+    #[rstest]
+    #[case("R 6 (#70c710)", Direction::East, 461937)]
+    #[case("D 5 (#0dc571)", Direction::South, 56407)]
+    #[case("L 2 (#5713f0)", Direction::East, 356671)]
+    #[case("D 2 (#d2c081)", Direction::South, 863240)]
+    #[case("L 2 (#8ceee2)", Direction::West, 577262)]
+    #[case("U 2 (#caa173)", Direction::North, 829975)]
+    fn test_lines(
+        #[case] line: &str,
+        #[case] direction: Direction,
+        #[case] distance: i64,
+    ) -> miette::Result<()> {
+        let plan_line = parse_complete(line, parse_plan_line)?;
+        assert_eq!(
+            Instruction {
+                direction,
+                distance
+            },
+            plan_line.color_instruction
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_color() {
+        assert!(parse_complete("R 6 (#70c714)", parse_plan_line).is_err());
+    }
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        assert_eq!(952408144115, process(INPUT)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,19 @@
+use aoc_common::solution::{Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+pub mod system;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    fn part1(input: &str) -> miette::Result<Answer> {
+        Ok(part1::process(input)?.into())
+    }
+
+    fn part2(input: &str) -> miette::Result<Answer> {
+        Ok(part2::process(input)?.into())
+    }
+}
@@ -0,0 +1,82 @@
+use crate::system::parse_system;
+use aoc_common::{custom_error::AocError, parsing::parse_complete};
+
+#[tracing::instrument]
+pub fn process(input: &str) -> miette::Result<u32, AocError> {
+    let (system, parts) = parse_complete(input, parse_system)?;
+
+    let mut total = 0;
+    for part in &parts {
+        if system.accepts(part)? {
+            total += part.total_rating();
+        }
+    }
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::Part;
+    use rstest::rstest;
+
+    const INPUT: &str = "px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}";
+
+    #[rstest]
+    #[case([787, 2655, 1222, 2876], true)]
+    #[case([1679, 44, 2067, 496], false)]
+    #[case([2036, 264, 79, 2244], true)]
+    #[case([2461, 1339, 466, 291], false)]
+    #[case([2127, 1623, 2188, 1013], true)]
+    fn test_parts(#[case] ratings: [u32; 4], #[case] expected: bool) -> miette::Result<()> {
+        let (system, _) = parse_complete(INPUT, parse_system)?;
+        assert_eq!(expected, system.accepts(&Part { ratings })?);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("in{x<10:A,R}\n\n{x=1,m=2,a=3}")]
+    #[case("in{y<10:A,R}\n\n{x=1,m=2,a=3,s=4}")]
+    #[case("in{x<10:A,R}\n{x=1,m=2,a=3,s=4}")]
+    fn test_invalid_input(#[case] input: &str) {
+        assert!(matches!(process(input), Err(AocError::ParseError { .. })));
+    }
+
+    #[test]
+    fn test_unknown_workflow() {
+        assert!(matches!(
+            process("in{x<10:A,abc}\n\n{x=100,m=2,a=3,s=4}"),
+            Err(AocError::NoSolution(_))
+        ));
+    }
+
+    #[test]
+    fn test_cyclic_workflows() {
+        assert!(matches!(
+            process("in{x<5:ab,A}\nab{in}\n\n{x=1,m=2,a=3,s=4}"),
+            Err(AocError::NoSolution(_))
+        ));
+    }
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        assert_eq!(19114, process(INPUT)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+use crate::system::{parse_system, PartRanges};
+use aoc_common::{custom_error::AocError, parsing::parse_complete};
+
+// Every category is rated from 1 to 4000
+const RATINGS: std::ops::Range<u32> = 1..4001;
+
+#[tracing::instrument]
+pub fn process(input: &str) -> miette::Result<u64, AocError> {
+    let (system, _) = parse_complete(input, parse_system)?;
+
+    system.accepted_combinations(PartRanges::new(RATINGS))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("in{A}", 4000u64.pow(4))]
+    #[case("in{R}", 0)]
+    #[case("in{x<2001:A,R}", 2000 * 4000u64.pow(3))]
+    #[case("in{x>1000:b,R}\nb{x<1011:A,m>3990:A,R}", 10 * 4000u64.pow(3) + 2990 * 10 * 4000u64.pow(2))]
+    fn test_workflows(#[case] workflows: &str, #[case] expected: u64) -> miette::Result<()> {
+        let input = format!("{workflows}\n\n{{x=1,m=1,a=1,s=1}}");
+        assert_eq!(expected, process(&input)?);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("in{x<5:ab,A}\nab{in}")]
+    #[case("in{x<5:ab,A}\nab{x<3:in,R}")]
+    fn test_cyclic_workflows(#[case] workflows: &str) {
+        let input = format!("{workflows}\n\n{{x=1,m=1,a=1,s=1}}");
+        assert!(matches!(process(&input), Err(AocError::NoSolution(_))));
+    }
+
+    #[test]
+    fn test_overflowing_condition() -> miette::Result<()> {
+        let input = "in{x>4294967295:R,A}\n\n{x=1,m=1,a=1,s=1}";
+        assert_eq!(4000u64.pow(4), process(input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        let input = "px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}";
+        assert_eq!(167409079868000, process(input)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,294 @@
+use aoc_common::{custom_error::AocError, parsing::lines};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{self, alpha1, line_ending, one_of},
+    multi::separated_list1,
+    sequence::{delimited, pair, preceded, separated_pair, terminated, tuple},
+    IResult, Parser,
+};
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+    ops::Range,
+};
+
+/// Workflow every part is first sent to
+const START: &str = "in";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Category {
+    X, // Extremely cool looking
+    M, // Musical
+    A, // Aerodynamic
+    S, // Shiny
+}
+
+impl Category {
+    fn from_char(c: char) -> Self {
+        match c {
+            'x' => Self::X,
+            'm' => Self::M,
+            'a' => Self::A,
+            's' => Self::S,
+            _ => unreachable!("only recognized characters are parsed"),
+        }
+    }
+}
+
+/// Machine part with a rating in each category
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Part {
+    pub ratings: [u32; 4],
+}
+
+impl Part {
+    pub fn total_rating(&self) -> u32 {
+        self.ratings.iter().sum()
+    }
+}
+
+/// Every part whose ratings lie within the range of each category
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartRanges {
+    pub ranges: [Range<u32>; 4],
+}
+
+impl PartRanges {
+    pub fn new(ratings: Range<u32>) -> Self {
+        Self {
+            ranges: [(); 4].map(|_| ratings.clone()),
+        }
+    }
+
+    pub fn combinations(&self) -> u64 {
+        self.ranges.iter().map(|range| range.len() as u64).product()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.ranges.iter().any(|range| range.is_empty())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Condition {
+    category: Category,
+    ordering: Ordering,
+    value: u32,
+}
+
+impl Condition {
+    fn matches(&self, part: &Part) -> bool {
+        part.ratings[self.category as usize].cmp(&self.value) == self.ordering
+    }
+
+    // Split the ranges into the parts matching the condition and the others
+    fn split(&self, ranges: PartRanges) -> (PartRanges, PartRanges) {
+        let range = &ranges.ranges[self.category as usize];
+        let (matching, rest) = match self.ordering {
+            Ordering::Less => (
+                range.start..self.value.clamp(range.start, range.end),
+                self.value.clamp(range.start, range.end)..range.end,
+            ),
+            _ => (
+                self.value.saturating_add(1).clamp(range.start, range.end)..range.end,
+                range.start..self.value.saturating_add(1).clamp(range.start, range.end),
+            ),
+        };
+
+        let mut matching_ranges = ranges.clone();
+        matching_ranges.ranges[self.category as usize] = matching;
+        let mut rest_ranges = ranges;
+        rest_ranges.ranges[self.category as usize] = rest;
+        (matching_ranges, rest_ranges)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target<'a> {
+    Accept,
+    Reject,
+    Workflow(&'a str),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Rule<'a> {
+    condition: Option<Condition>,
+    target: Target<'a>,
+}
+
+#[derive(Debug)]
+struct Workflow<'a> {
+    rules: Vec<Rule<'a>>,
+}
+
+impl<'a> Workflow<'a> {
+    fn target(&self, part: &Part) -> Option<Target<'a>> {
+        self.rules
+            .iter()
+            .find(|rule| match rule.condition {
+                Some(condition) => condition.matches(part),
+                None => true,
+            })
+            .map(|rule| rule.target)
+    }
+}
+
+/// Workflows sorting parts, by name
+#[derive(Debug)]
+pub struct System<'a> {
+    workflows: HashMap<&'a str, Workflow<'a>>,
+}
+
+impl<'a> System<'a> {
+    fn workflow(&self, name: &str) -> Result<&Workflow<'a>, AocError> {
+        self.workflows
+            .get(name)
+            .ok_or_else(|| AocError::NoSolution(format!("unknown workflow `{name}`")))
+    }
+
+    fn cycle_error(name: &str) -> AocError {
+        AocError::NoSolution(format!("parts are sent back to workflow `{name}` forever"))
+    }
+
+    /// Whether the part ends up accepted, once sent through the workflows from `in`
+    pub fn accepts(&self, part: &Part) -> Result<bool, AocError> {
+        let mut name = START;
+        let mut visited = HashSet::new();
+        loop {
+            // Workflows are deterministic, so a part coming back to one never leaves the loop
+            if !visited.insert(name) {
+                return Err(Self::cycle_error(name));
+            }
+            let workflow = self.workflow(name)?;
+            match workflow.target(part) {
+                Some(Target::Accept) => return Ok(true),
+                Some(Target::Reject) => return Ok(false),
+                Some(Target::Workflow(next)) => name = next,
+                None => {
+                    return Err(AocError::NoSolution(format!(
+                        "no rule of workflow `{name}` applies"
+                    )))
+                }
+            }
+        }
+    }
+
+    /// Number of parts within the ranges that end up accepted
+    pub fn accepted_combinations(&self, ranges: PartRanges) -> Result<u64, AocError> {
+        self.count_accepted(START, ranges, &mut Vec::new())
+    }
+
+    // `path` holds the workflows the ranges went through, any part coming back to one of them loops forever
+    fn count_accepted(
+        &self,
+        name: &'a str,
+        mut ranges: PartRanges,
+        path: &mut Vec<&'a str>,
+    ) -> Result<u64, AocError> {
+        if path.contains(&name) {
+            return Err(Self::cycle_error(name));
+        }
+        path.push(name);
+        let mut count = 0;
+        for rule in &self.workflow(name)?.rules {
+            let matching = match rule.condition {
+                Some(condition) => {
+                    let (matching, rest) = condition.split(ranges);
+                    ranges = rest;
+                    matching
+                }
+                None => std::mem::replace(&mut ranges, PartRanges::new(0..0)),
+            };
+            if !matching.is_empty() {
+                count += match rule.target {
+                    Target::Accept => matching.combinations(),
+                    Target::Reject => 0,
+                    Target::Workflow(next) => self.count_accepted(next, matching, path)?,
+                };
+            }
+            if ranges.is_empty() {
+                break;
+            }
+        }
+        path.pop();
+        Ok(count)
+    }
+}
+
+fn parse_target(input: &str) -> IResult<&str, Target<'_>> {
+    alpha1
+        .map(|name| match name {
+            "A" => Target::Accept,
+            "R" => Target::Reject,
+            name => Target::Workflow(name),
+        })
+        .parse(input)
+}
+
+fn parse_condition(input: &str) -> IResult<&str, Condition> {
+    tuple((
+        one_of("xmas").map(Category::from_char),
+        one_of("<>").map(|c| match c {
+            '<' => Ordering::Less,
+            _ => Ordering::Greater,
+        }),
+        complete::u32,
+    ))
+    .map(|(category, ordering, value)| Condition {
+        category,
+        ordering,
+        value,
+    })
+    .parse(input)
+}
+
+fn parse_rule(input: &str) -> IResult<&str, Rule<'_>> {
+    alt((
+        separated_pair(parse_condition, tag(":"), parse_target).map(|(condition, target)| Rule {
+            condition: Some(condition),
+            target,
+        }),
+        parse_target.map(|target| Rule {
+            condition: None,
+            target,
+        }),
+    ))(input)
+}
+
+// `px{a<2006:qkq,m>2090:A,rfg}`
+fn parse_workflow(input: &str) -> IResult<&str, (&str, Workflow<'_>)> {
+    pair(
+        alpha1,
+        delimited(tag("{"), separated_list1(tag(","), parse_rule), tag("}"))
+            .map(|rules| Workflow { rules }),
+    )(input)
+}
+
+// `{x=787,m=2655,a=1222,s=2876}`
+fn parse_part(input: &str) -> IResult<&str, Part> {
+    delimited(
+        tag("{"),
+        tuple((
+            preceded(tag("x="), complete::u32),
+            preceded(tag(",m="), complete::u32),
+            preceded(tag(",a="), complete::u32),
+            preceded(tag(",s="), complete::u32),
+        )),
+        tag("}"),
+    )
+    .map(|(x, m, a, s)| Part {
+        ratings: [x, m, a, s],
+    })
+    .parse(input)
+}
+
+pub fn parse_system(input: &str) -> IResult<&str, (System<'_>, Vec<Part>)> {
+    separated_pair(
+        lines(parse_workflow).map(|workflows| System {
+            workflows: workflows.into_iter().collect(),
+        }),
+        terminated(line_ending, line_ending),
+        lines(parse_part),
+    )(input)
+}
@@ -0,0 +1,266 @@
+use aoc_common::parsing::lines;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{alpha1, char},
+    multi::separated_list1,
+    sequence::{pair, separated_pair},
+    IResult, Parser,
+};
+use petgraph::{
+    dot::{Config, Dot},
+    graph::{DiGraph, NodeIndex},
+    Direction,
+};
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::{self, Display},
+};
+
+/// Module receiving the pulse of the button
+const BROADCASTER: &str = "broadcaster";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pulse {
+    Low,
+    High,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Module {
+    Broadcaster,
+    FlipFlop { on: bool },
+    Conjunction { memory: HashMap<NodeIndex, Pulse> },
+    // Only receives pulses, such as `output` or `rx`
+    Untyped,
+}
+
+impl Module {
+    // Pulse sent to every destination upon receiving `pulse` from `from`, if any
+    fn receive(&mut self, from: NodeIndex, pulse: Pulse) -> Option<Pulse> {
+        match self {
+            Module::Broadcaster => Some(pulse),
+            Module::FlipFlop { on } => match pulse {
+                Pulse::High => None,
+                Pulse::Low => {
+                    *on = !*on;
+                    Some(if *on { Pulse::High } else { Pulse::Low })
+                }
+            },
+            Module::Conjunction { memory } => {
+                memory.insert(from, pulse);
+                if memory.values().all(|&p| p == Pulse::High) {
+                    Some(Pulse::Low)
+                } else {
+                    Some(Pulse::High)
+                }
+            }
+            Module::Untyped => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Node<'a> {
+    pub name: &'a str,
+    pub module: Module,
+}
+
+impl Display for Node<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let prefix = match self.module {
+            Module::FlipFlop { .. } => "%",
+            Module::Conjunction { .. } => "&",
+            Module::Broadcaster | Module::Untyped => "",
+        };
+        write!(f, "{prefix}{}", self.name)
+    }
+}
+
+/// Pulse travelling from a module to one of its destinations
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Signal {
+    pub from: NodeIndex,
+    pub to: NodeIndex,
+    pub pulse: Pulse,
+}
+
+/// Modules connected by cables, each edge going from a module to one of its destinations
+#[derive(Debug, Clone)]
+pub struct Network<'a> {
+    graph: DiGraph<Node<'a>, ()>,
+    indices: HashMap<&'a str, NodeIndex>,
+}
+
+impl<'a> Network<'a> {
+    fn new(modules: Vec<(Node<'a>, Vec<&'a str>)>) -> Self {
+        let mut graph = DiGraph::new();
+        let mut indices = HashMap::new();
+        let destinations: Vec<_> = modules
+            .into_iter()
+            .map(|(node, destinations)| {
+                let idx = graph.add_node(node.clone());
+                indices.insert(node.name, idx);
+                (idx, destinations)
+            })
+            .collect();
+
+        for (idx, names) in destinations {
+            for name in names {
+                let to = *indices.entry(name).or_insert_with(|| {
+                    graph.add_node(Node {
+                        name,
+                        module: Module::Untyped,
+                    })
+                });
+                graph.add_edge(idx, to, ());
+            }
+        }
+
+        // Conjunctions initially remember a low pulse for each of their inputs
+        for idx in graph.node_indices() {
+            let inputs: Vec<_> = graph.neighbors_directed(idx, Direction::Incoming).collect();
+            if let Module::Conjunction { memory } = &mut graph[idx].module {
+                memory.extend(inputs.into_iter().map(|input| (input, Pulse::Low)));
+            }
+        }
+
+        Self { graph, indices }
+    }
+
+    pub fn graph(&self) -> &DiGraph<Node<'a>, ()> {
+        &self.graph
+    }
+
+    pub fn index(&self, name: &str) -> Option<NodeIndex> {
+        self.indices.get(name).copied()
+    }
+
+    /// Push the button once, calling `on_signal` for every pulse sent until the network settles
+    pub fn press_button(&mut self, mut on_signal: impl FnMut(&Signal)) {
+        let Some(broadcaster) = self.index(BROADCASTER) else {
+            return;
+        };
+        let mut queue = VecDeque::from([Signal {
+            from: broadcaster,
+            to: broadcaster,
+            pulse: Pulse::Low,
+        }]);
+
+        while let Some(signal) = queue.pop_front() {
+            on_signal(&signal);
+            let Some(pulse) = self.graph[signal.to]
+                .module
+                .receive(signal.from, signal.pulse)
+            else {
+                continue;
+            };
+            queue.extend(
+                self.graph
+                    .neighbors(signal.to)
+                    .map(|to| Signal {
+                        from: signal.to,
+                        to,
+                        pulse,
+                    })
+                    // Neighbors are listed from the most recently added edge
+                    .collect::<Vec<_>>()
+                    .into_iter()
+                    .rev(),
+            );
+        }
+    }
+
+    /// Graphviz representation of the network, for debugging
+    pub fn dot(&self) -> String {
+        let labelled = self.graph.map(|_, node| node.to_string(), |_, _| "");
+        Dot::with_config(&labelled, &[Config::EdgeNoLabel]).to_string()
+    }
+}
+
+fn parse_node(input: &str) -> IResult<&str, Node<'_>> {
+    alt((
+        tag(BROADCASTER).map(|name| Node {
+            name,
+            module: Module::Broadcaster,
+        }),
+        pair(char('%'), alpha1).map(|(_, name)| Node {
+            name,
+            module: Module::FlipFlop { on: false },
+        }),
+        pair(char('&'), alpha1).map(|(_, name)| Node {
+            name,
+            module: Module::Conjunction {
+                memory: HashMap::new(),
+            },
+        }),
+    ))(input)
+}
+
+// `%a -> inv, con`
+fn parse_module(input: &str) -> IResult<&str, (Node<'_>, Vec<&str>)> {
+    separated_pair(parse_node, tag(" -> "), separated_list1(tag(", "), alpha1))(input)
+}
+
+pub fn parse_network(input: &str) -> IResult<&str, Network<'_>> {
+    lines(parse_module).map(Network::new).parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::parsing::parse_complete;
+
+    #[test]
+    fn test_press_button() -> miette::Result<()> {
+        let mut network = parse_complete(
+            "broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output",
+            parse_network,
+        )?;
+
+        let mut signals = vec![];
+        network.press_button(|signal| signals.push(*signal));
+
+        let name = |idx: NodeIndex| network.graph()[idx].name;
+        // Skip the pulse of the button
+        let sent: Vec<_> = signals
+            .iter()
+            .skip(1)
+            .map(|signal| {
+                format!(
+                    "{} -{:?}-> {}",
+                    name(signal.from),
+                    signal.pulse,
+                    name(signal.to)
+                )
+            })
+            .collect();
+        assert_eq!(
+            vec![
+                "broadcaster -Low-> a",
+                "a -High-> inv",
+                "a -High-> con",
+                "inv -Low-> b",
+                "con -High-> output",
+                "b -High-> con",
+                "con -Low-> output",
+            ],
+            sent
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_dot() -> miette::Result<()> {
+        let network = parse_complete("broadcaster -> a\n%a -> b\n&b -> a", parse_network)?;
+        let dot = network.dot();
+        assert!(dot.contains("label = \"%a\""));
+        assert!(dot.contains("label = \"&b\""));
+        assert!(dot.contains("1 -> 2"));
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use crate::network::{parse_network, Pulse};
+use aoc_common::{custom_error::AocError, parsing::parse_complete};
+
+const PRESSES: usize = 1000;
+
+#[tracing::instrument]
+pub fn process(input: &str) -> miette::Result<usize, AocError> {
+    let mut network = parse_complete(input, parse_network)?;
+
+    let (mut low, mut high) = (0, 0);
+    for _ in 0..PRESSES {
+        network.press_button(|signal| match signal.pulse {
+            Pulse::Low => low += 1,
+            Pulse::High => high += 1,
+        });
+    }
+
+    Ok(low * high)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(
+        "broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a",
+        32000000
+    )]
+    #[case(
+        "broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output",
+        11687500
+    )]
+    fn test_process(#[case] input: &str, #[case] expected: usize) -> miette::Result<()> {
+        assert_eq!(expected, process(input)?);
+        Ok(())
+    }
+}
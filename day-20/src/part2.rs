@@ -0,0 +1,112 @@
+use crate::network::{parse_network, Module, Pulse};
+use aoc_common::{custom_error::AocError, parsing::parse_complete};
+use petgraph::Direction;
+use std::collections::HashMap;
+
+const MACHINE: &str = "rx";
+
+// Give up on finding cycles after this many presses
+const MAX_PRESSES: u64 = 1 << 20;
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn lcm(a: u64, b: u64) -> u64 {
+    a / gcd(a, b) * b
+}
+
+#[tracing::instrument]
+pub fn process(input: &str) -> miette::Result<u64, AocError> {
+    let mut network = parse_complete(input, parse_network)?;
+
+    // The machine is fed by a single conjunction, which sends it a low pulse once all of its
+    // inputs have sent a high pulse during the same press
+    let machine = network
+        .index(MACHINE)
+        .ok_or_else(|| AocError::NoSolution(format!("no `{MACHINE}` module")))?;
+    let graph = network.graph();
+    let mut feeders = graph.neighbors_directed(machine, Direction::Incoming);
+    let feeder = match (feeders.next(), feeders.next()) {
+        (Some(feeder), None) if matches!(graph[feeder].module, Module::Conjunction { .. }) => {
+            feeder
+        }
+        _ => {
+            return Err(AocError::NoSolution(format!(
+                "`{MACHINE}` is not fed by a single conjunction"
+            )))
+        }
+    };
+    let inputs: Vec<_> = graph
+        .neighbors_directed(feeder, Direction::Incoming)
+        .collect();
+
+    // Presses at which each input first sent a high pulse to the feeder
+    let mut first_high = HashMap::new();
+    let mut cycles = HashMap::new();
+    let mut presses = 0;
+    while cycles.len() < inputs.len() {
+        presses += 1;
+        if presses > MAX_PRESSES {
+            return Err(AocError::NoSolution(format!(
+                "inputs of `{MACHINE}`'s feeder do not cycle"
+            )));
+        }
+        network.press_button(|signal| {
+            if signal.to == feeder && signal.pulse == Pulse::High {
+                match first_high.get(&signal.from) {
+                    None => {
+                        first_high.insert(signal.from, presses);
+                    }
+                    Some(&first) if first < presses => {
+                        cycles.entry(signal.from).or_insert(presses - first);
+                    }
+                    Some(_) => {}
+                }
+            }
+        });
+    }
+
+    // Cycles must start from the first press for their combination to be their least common multiple
+    if inputs
+        .iter()
+        .any(|input| first_high[input] != cycles[input])
+    {
+        return Err(AocError::NoSolution(format!(
+            "inputs of `{MACHINE}`'s feeder have offset cycles"
+        )));
+    }
+
+    Ok(cycles.values().copied().fold(1, lcm))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        // Counters of period 2 and 4 feeding the machine through `f`
+        let input = "broadcaster -> a, b
+%a -> ia
+&ia -> f
+%b -> c
+%c -> ic
+&ic -> f
+&f -> rx";
+        assert_eq!(4, process(input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_no_machine() {
+        assert!(matches!(
+            process("broadcaster -> a\n%a -> output"),
+            Err(AocError::NoSolution(_))
+        ));
+    }
+}
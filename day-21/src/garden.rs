@@ -0,0 +1,89 @@
+use aoc_common::{
+    grid::{Grid, UnrecognizedChar},
+    parsing::grid,
+};
+use nom::{combinator::map_opt, IResult};
+use std::collections::{HashSet, VecDeque};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Plot,  // .
+    Rock,  // #
+    Start, // S
+}
+
+impl TryFrom<char> for Tile {
+    type Error = UnrecognizedChar;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(Self::Plot),
+            '#' => Ok(Self::Rock),
+            'S' => Ok(Self::Start),
+            _ => Err(UnrecognizedChar(c)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Garden {
+    tiles: Grid<Tile>,
+    start: [usize; 2],
+}
+
+impl Garden {
+    /// Side of the map, which is square
+    pub fn size(&self) -> usize {
+        self.tiles.nrows()
+    }
+
+    // Tile at some position, the map being repeated infinitely in every direction if `tiled`
+    fn tile(&self, [y, x]: [i64; 2], tiled: bool) -> Option<Tile> {
+        let [nrows, ncols] = [self.tiles.nrows() as i64, self.tiles.ncols() as i64];
+        let inside = (0..nrows).contains(&y) && (0..ncols).contains(&x);
+        if !(tiled || inside) {
+            return None;
+        }
+        Some(self.tiles[[y.rem_euclid(nrows) as usize, x.rem_euclid(ncols) as usize]])
+    }
+
+    /// Number of plots the elf can end on after each number of steps
+    ///
+    /// A plot reached in `d` steps can be reached again in `d + 2` by stepping back and forth,
+    /// so these are the plots at a distance of at most `steps` with the same parity.
+    pub fn reachable_plots(&self, steps: &[usize], tiled: bool) -> Vec<usize> {
+        let max_steps = steps.iter().copied().max().unwrap_or(0);
+        // Plots by distance from the start (BFS)
+        let start = self.start.map(|i| i as i64);
+        let mut visited = HashSet::from([start]);
+        let mut queue = VecDeque::from([(start, 0)]);
+        let mut at_distance = vec![0; max_steps + 1];
+        while let Some(([y, x], distance)) = queue.pop_front() {
+            at_distance[distance] += 1;
+            if distance == max_steps {
+                continue;
+            }
+            for next in [[y - 1, x], [y + 1, x], [y, x - 1], [y, x + 1]] {
+                if self
+                    .tile(next, tiled)
+                    .is_some_and(|tile| tile != Tile::Rock)
+                    && visited.insert(next)
+                {
+                    queue.push_back((next, distance + 1));
+                }
+            }
+        }
+
+        steps
+            .iter()
+            .map(|&s| at_distance[..=s].iter().skip(s % 2).step_by(2).sum())
+            .collect()
+    }
+}
+
+pub fn parse_garden(input: &str) -> IResult<&str, Garden> {
+    map_opt(grid, |tiles: Grid<Tile>| {
+        let start = tiles.position(|&tile| tile == Tile::Start)?;
+        Some(Garden { tiles, start })
+    })(input)
+}
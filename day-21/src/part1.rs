@@ -0,0 +1,43 @@
+use crate::garden::parse_garden;
+use aoc_common::{custom_error::AocError, parsing::parse_complete};
+
+const STEPS: usize = 64;
+
+pub fn process_steps(input: &str, steps: usize) -> miette::Result<usize, AocError> {
+    let garden = parse_complete(input, parse_garden)?;
+
+    Ok(garden.reachable_plots(&[steps], false)[0])
+}
+
+#[tracing::instrument]
+pub fn process(input: &str) -> miette::Result<usize, AocError> {
+    process_steps(input, STEPS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const INPUT: &str = "...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........";
+
+    #[rstest]
+    #[case(1, 2)]
+    #[case(2, 4)]
+    #[case(3, 6)]
+    #[case(6, 16)]
+    fn test_steps(#[case] steps: usize, #[case] expected: usize) -> miette::Result<()> {
+        assert_eq!(expected, process_steps(INPUT, steps)?);
+        Ok(())
+    }
+}
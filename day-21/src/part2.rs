@@ -0,0 +1,77 @@
+use crate::garden::parse_garden;
+use aoc_common::{custom_error::AocError, extrapolation::Extrapolation, parsing::parse_complete};
+
+const STEPS: usize = 26501365;
+
+// Quadratic growth is fitted on this many samples
+const SAMPLES: usize = 3;
+
+/// Plots reachable on the infinitely tiled map, extrapolated from a few map-sized step increments
+///
+/// The row and column of the start are clear, so the reachable area grows by whole maps each `size`
+/// steps and the number of plots is quadratic in the number of maps crossed.
+pub fn process_steps(input: &str, steps: usize) -> miette::Result<usize, AocError> {
+    let garden = parse_complete(input, parse_garden)?;
+    let size = garden.size();
+
+    let maps = steps / size;
+    let sample_steps: Vec<_> = (0..SAMPLES).map(|k| steps % size + k * size).collect();
+    let samples = garden.reachable_plots(&sample_steps, true);
+    if maps < SAMPLES {
+        return Ok(samples[maps]);
+    }
+
+    let samples: Vec<_> = samples.into_iter().map(|s| s as i64).collect();
+    let plots = Extrapolation::new(&samples)
+        .nth(maps - SAMPLES)
+        .expect("extrapolation is infinite");
+    Ok(plots as usize)
+}
+
+#[tracing::instrument]
+pub fn process(input: &str) -> miette::Result<usize, AocError> {
+    process_steps(input, STEPS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(6, 16)]
+    #[case(10, 50)]
+    #[case(50, 1594)]
+    #[case(100, 6536)]
+    fn test_tiled_steps(#[case] steps: usize, #[case] expected: usize) -> miette::Result<()> {
+        let input = "...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........";
+        let garden = parse_complete(input, parse_garden)?;
+        assert_eq!(vec![expected], garden.reachable_plots(&[steps], true));
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(3, 16)]
+    #[case(52, 2809)]
+    #[case(1002, 1006009)]
+    fn test_process_steps(#[case] steps: usize, #[case] expected: usize) -> miette::Result<()> {
+        // Without rocks, the plots reachable in `n` steps form a diamond of `(n + 1)²` plots
+        let input = ".....
+.....
+..S..
+.....
+.....";
+        assert_eq!(expected, process_steps(input, steps)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,185 @@
+use aoc_common::parsing::lines;
+use nom::{
+    bytes::complete::tag,
+    character::complete,
+    sequence::{separated_pair, tuple},
+    IResult, Parser,
+};
+use nom_supreme::ParserExt;
+use std::collections::{HashMap, VecDeque};
+
+/// Line of cubes between two opposite corners, `start` being the lowest on every axis
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Brick {
+    pub start: [u32; 3],
+    pub end: [u32; 3],
+}
+
+impl Brick {
+    fn new(a: [u32; 3], b: [u32; 3]) -> Self {
+        Self {
+            start: [0, 1, 2].map(|axis| a[axis].min(b[axis])),
+            end: [0, 1, 2].map(|axis| a[axis].max(b[axis])),
+        }
+    }
+
+    // Columns covered by the brick when seen from above
+    fn footprint(&self) -> impl Iterator<Item = [u32; 2]> + '_ {
+        (self.start[0]..=self.end[0])
+            .flat_map(move |x| (self.start[1]..=self.end[1]).map(move |y| [x, y]))
+    }
+
+    fn drop_to(&mut self, z: u32) {
+        self.end[2] -= self.start[2] - z;
+        self.start[2] = z;
+    }
+}
+
+/// Bricks once settled, along with which bricks rest directly on which
+#[derive(Debug)]
+pub struct Stack {
+    pub bricks: Vec<Brick>,
+    // Bricks resting on each brick, and bricks each brick rests on
+    supports: Vec<Vec<usize>>,
+    supported_by: Vec<Vec<usize>>,
+}
+
+impl Stack {
+    /// Let the bricks fall from the lowest one until they all rest on the ground or another brick
+    pub fn settle(mut bricks: Vec<Brick>) -> Self {
+        bricks.sort_by_key(|brick| brick.start[2]);
+
+        // Top of the highest brick in each column, along with that brick
+        let mut heights: HashMap<[u32; 2], (u32, usize)> = HashMap::new();
+        let mut supports = vec![vec![]; bricks.len()];
+        let mut supported_by = vec![vec![]; bricks.len()];
+        for (idx, brick) in bricks.iter_mut().enumerate() {
+            let below: Vec<_> = brick
+                .footprint()
+                .filter_map(|column| heights.get(&column))
+                .collect();
+            let floor = below.iter().map(|&&(top, _)| top).max().unwrap_or(0);
+
+            let mut supporting: Vec<_> = below
+                .iter()
+                .filter(|&&&(top, _)| top == floor)
+                .map(|&&(_, below_idx)| below_idx)
+                .collect();
+            supporting.sort_unstable();
+            supporting.dedup();
+            for &below_idx in &supporting {
+                supports[below_idx].push(idx);
+            }
+            supported_by[idx] = supporting;
+
+            brick.drop_to(floor + 1);
+            for column in brick.footprint() {
+                heights.insert(column, (brick.end[2], idx));
+            }
+        }
+
+        Self {
+            bricks,
+            supports,
+            supported_by,
+        }
+    }
+
+    /// Whether removing the brick leaves every other brick in place
+    pub fn can_disintegrate(&self, idx: usize) -> bool {
+        self.supports[idx]
+            .iter()
+            .all(|&above| self.supported_by[above].len() > 1)
+    }
+
+    /// Number of other bricks falling in a chain reaction when the brick is disintegrated
+    pub fn chain_reaction(&self, idx: usize) -> usize {
+        let mut fallen = vec![false; self.bricks.len()];
+        fallen[idx] = true;
+        let mut queue = VecDeque::from([idx]);
+        let mut count = 0;
+        while let Some(current) = queue.pop_front() {
+            for &above in &self.supports[current] {
+                if !fallen[above] && self.supported_by[above].iter().all(|&b| fallen[b]) {
+                    fallen[above] = true;
+                    count += 1;
+                    queue.push_back(above);
+                }
+            }
+        }
+        count
+    }
+}
+
+fn parse_position(input: &str) -> IResult<&str, [u32; 3]> {
+    tuple((
+        complete::u32,
+        tag(",").precedes(complete::u32),
+        tag(",").precedes(complete::u32),
+    ))
+    .map(|(x, y, z)| [x, y, z])
+    .parse(input)
+}
+
+// `1,0,1~1,2,1`
+pub fn parse_bricks(input: &str) -> IResult<&str, Vec<Brick>> {
+    lines(separated_pair(parse_position, tag("~"), parse_position).map(|(a, b)| Brick::new(a, b)))(
+        input,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::parsing::parse_complete;
+
+    #[test]
+    fn test_settle() -> miette::Result<()> {
+        let input = "1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9";
+        let stack = Stack::settle(parse_complete(input, parse_bricks)?);
+        let expected = [
+            ([1, 0, 1], [1, 2, 1]),
+            ([0, 0, 2], [2, 0, 2]),
+            ([0, 2, 2], [2, 2, 2]),
+            ([0, 0, 3], [0, 2, 3]),
+            ([2, 0, 3], [2, 2, 3]),
+            ([0, 1, 4], [2, 1, 4]),
+            ([1, 1, 5], [1, 1, 6]),
+        ]
+        .map(|(start, end)| Brick { start, end });
+        assert_eq!(expected.as_slice(), stack.bricks);
+        // A supports B and C, which both support D and E, which both support F, which supports G
+        assert_eq!(
+            vec![
+                vec![1, 2],
+                vec![3, 4],
+                vec![3, 4],
+                vec![5],
+                vec![5],
+                vec![6],
+                vec![]
+            ],
+            stack.supports
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_unordered_corners() -> miette::Result<()> {
+        let bricks = parse_complete("1,2,5~1,0,3", parse_bricks)?;
+        assert_eq!(
+            vec![Brick {
+                start: [1, 0, 3],
+                end: [1, 2, 5]
+            }],
+            bricks
+        );
+        Ok(())
+    }
+}
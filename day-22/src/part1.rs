@@ -0,0 +1,29 @@
+use crate::bricks::{parse_bricks, Stack};
+use aoc_common::{custom_error::AocError, parsing::parse_complete};
+
+#[tracing::instrument]
+pub fn process(input: &str) -> miette::Result<usize, AocError> {
+    let stack = Stack::settle(parse_complete(input, parse_bricks)?);
+
+    Ok((0..stack.bricks.len())
+        .filter(|&idx| stack.can_disintegrate(idx))
+        .count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        let input = "1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9";
+        assert_eq!(5, process(input)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use crate::bricks::{parse_bricks, Stack};
+use aoc_common::{custom_error::AocError, parsing::parse_complete};
+
+#[tracing::instrument]
+pub fn process(input: &str) -> miette::Result<usize, AocError> {
+    let stack = Stack::settle(parse_complete(input, parse_bricks)?);
+
+    Ok((0..stack.bricks.len())
+        .map(|idx| stack.chain_reaction(idx))
+        .sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const INPUT: &str = "1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9";
+
+    #[rstest]
+    #[case(0, 6)]
+    #[case(1, 0)]
+    #[case(5, 1)]
+    #[case(6, 0)]
+    fn test_chain_reaction(#[case] idx: usize, #[case] expected: usize) -> miette::Result<()> {
+        let stack = Stack::settle(parse_complete(INPUT, parse_bricks)?);
+        assert_eq!(expected, stack.chain_reaction(idx));
+        Ok(())
+    }
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        assert_eq!(7, process(INPUT)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,68 @@
+use crate::trails::parse_trail_map;
+use aoc_common::{custom_error::AocError, parsing::parse_complete};
+
+#[tracing::instrument]
+pub fn process(input: &str) -> miette::Result<usize, AocError> {
+    let map = parse_complete(input, parse_trail_map)?;
+
+    map.junction_graph(true).longest_hike()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#";
+
+    #[test]
+    fn test_junction_graph() -> miette::Result<()> {
+        let graph = parse_complete(INPUT, parse_trail_map)?.junction_graph(true);
+        assert_eq!(
+            vec![
+                [0, 1],
+                [22, 21],
+                [3, 11],
+                [5, 3],
+                [11, 21],
+                [13, 5],
+                [13, 13],
+                [19, 13],
+                [19, 19]
+            ],
+            graph.junctions
+        );
+        // Slopes only let paths be taken downhill, towards the end
+        assert_eq!(vec![(3, 15)], graph.edges[0]);
+        assert_eq!(vec![(6, 24), (4, 30)], graph.edges[2]);
+        assert!(graph.edges[1].is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        assert_eq!(94, process(INPUT)?);
+        Ok(())
+    }
+}
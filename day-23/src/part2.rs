@@ -0,0 +1,58 @@
+use crate::trails::parse_trail_map;
+use aoc_common::{custom_error::AocError, parsing::parse_complete};
+
+#[tracing::instrument]
+pub fn process(input: &str) -> miette::Result<usize, AocError> {
+    let map = parse_complete(input, parse_trail_map)?;
+
+    map.junction_graph(false).longest_hike()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#";
+
+    #[test]
+    fn test_junction_graph() -> miette::Result<()> {
+        let graph = parse_complete(INPUT, parse_trail_map)?.junction_graph(false);
+        assert_eq!(9, graph.junctions.len());
+        assert_eq!(vec![(3, 22), (6, 24), (4, 30)], graph.edges[2]);
+        // Without slopes, every path can be taken both ways
+        for (from, edges) in graph.edges.iter().enumerate() {
+            for &(to, length) in edges {
+                assert!(graph.edges[to].contains(&(from, length)));
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        assert_eq!(154, process(INPUT)?);
+        Ok(())
+    }
+}
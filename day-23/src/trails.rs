@@ -0,0 +1,164 @@
+use aoc_common::{
+    custom_error::AocError,
+    grid::{Direction, Grid, UnrecognizedChar},
+    parsing::grid,
+};
+use nom::{combinator::map_opt, IResult};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Path,             // .
+    Forest,           // #
+    Slope(Direction), // ^>v<
+}
+
+impl TryFrom<char> for Tile {
+    type Error = UnrecognizedChar;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(Self::Path),
+            '#' => Ok(Self::Forest),
+            '^' => Ok(Self::Slope(Direction::North)),
+            '>' => Ok(Self::Slope(Direction::East)),
+            'v' => Ok(Self::Slope(Direction::South)),
+            '<' => Ok(Self::Slope(Direction::West)),
+            _ => Err(UnrecognizedChar(c)),
+        }
+    }
+}
+
+impl Tile {
+    // Whether the tile can be entered or left going in some direction
+    fn allows(&self, direction: Direction, slippery: bool) -> bool {
+        match self {
+            Tile::Path => true,
+            Tile::Forest => false,
+            Tile::Slope(slope) => !slippery || *slope == direction,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct TrailMap {
+    tiles: Grid<Tile>,
+    start: [usize; 2],
+    end: [usize; 2],
+}
+
+/// Trail map reduced to the start, the end and the junctions between paths
+///
+/// Edges are the paths between two junctions, with their length in steps.
+#[derive(Debug)]
+pub struct JunctionGraph {
+    /// Position of each junction, the start and end coming first
+    pub junctions: Vec<[usize; 2]>,
+    /// Junctions reachable from each junction, with the length of the path
+    pub edges: Vec<Vec<(usize, usize)>>,
+}
+
+const START: usize = 0;
+const END: usize = 1;
+
+impl TrailMap {
+    // Moves from a tile, honoring slopes if `slippery`
+    fn moves(&self, idx: [usize; 2], slippery: bool) -> impl Iterator<Item = [usize; 2]> + '_ {
+        self.tiles
+            .neighbors4(idx)
+            .filter(move |&(direction, next)| {
+                self.tiles[idx].allows(direction, slippery)
+                    && self.tiles[next].allows(direction, slippery)
+            })
+            .map(|(_, next)| next)
+    }
+
+    /// Compress the map into the junctions between paths
+    pub fn junction_graph(&self, slippery: bool) -> JunctionGraph {
+        let mut junctions = vec![self.start, self.end];
+        junctions.extend(
+            self.tiles
+                .indexed_iter()
+                .filter(|&(_, &tile)| tile != Tile::Forest)
+                .map(|((y, x), _)| [y, x])
+                .filter(|&idx| self.moves(idx, false).count() > 2),
+        );
+        let indices: HashMap<_, _> = junctions
+            .iter()
+            .enumerate()
+            .map(|(i, &idx)| (idx, i))
+            .collect();
+
+        let edges = junctions
+            .iter()
+            .map(|&junction| {
+                // Follow each path leaving the junction up to the next one, paths being one tile wide
+                self.moves(junction, slippery)
+                    .filter_map(|first| {
+                        let mut previous = junction;
+                        let mut current = first;
+                        let mut length = 1;
+                        while !indices.contains_key(&current) {
+                            let next = self
+                                .moves(current, slippery)
+                                .find(|&next| next != previous)?;
+                            previous = current;
+                            current = next;
+                            length += 1;
+                        }
+                        Some((indices[&current], length))
+                    })
+                    .collect()
+            })
+            .collect();
+
+        JunctionGraph { junctions, edges }
+    }
+}
+
+impl JunctionGraph {
+    /// Length of the longest hike from the start to the end never visiting a tile twice
+    ///
+    /// Depth-first search over every path, junctions visited so far being kept in a bitmask.
+    pub fn longest_hike(&self) -> Result<usize, AocError> {
+        if self.junctions.len() > u64::BITS as usize {
+            return Err(AocError::NoSolution(format!(
+                "{} junctions do not fit in a bitmask",
+                self.junctions.len()
+            )));
+        }
+        // When the end can only be reached through one junction, reaching that junction ends the hike
+        let mut before_end = (0..self.junctions.len())
+            .filter(|&junction| self.edges[junction].iter().any(|&(next, _)| next == END));
+        let last = match (before_end.next(), before_end.next()) {
+            (Some(last), None) => Some(last),
+            _ => None,
+        };
+        self.longest_from(START, 1 << START, last)
+            .ok_or_else(|| AocError::NoSolution("no hike reaches the end".to_owned()))
+    }
+
+    fn longest_from(&self, junction: usize, visited: u64, last: Option<usize>) -> Option<usize> {
+        if junction == END {
+            return Some(0);
+        }
+        self.edges[junction]
+            .iter()
+            .filter(|&&(next, _)| visited & (1 << next) == 0)
+            .filter(|&&(next, _)| Some(junction) != last || next == END)
+            .filter_map(|&(next, length)| {
+                self.longest_from(next, visited | (1 << next), last)
+                    .map(|rest| length + rest)
+            })
+            .max()
+    }
+}
+
+pub fn parse_trail_map(input: &str) -> IResult<&str, TrailMap> {
+    map_opt(grid, |tiles: Grid<Tile>| {
+        let last = tiles.nrows().checked_sub(1)?;
+        let start = [0, tiles.row(0).iter().position(|&t| t == Tile::Path)?];
+        let end = [last, tiles.row(last).iter().position(|&t| t == Tile::Path)?];
+        Some(TrailMap { tiles, start, end })
+    })(input)
+}
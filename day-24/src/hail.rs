@@ -0,0 +1,85 @@
+use aoc_common::parsing::lines;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{self, space0, space1},
+    sequence::{delimited, preceded, separated_pair, tuple},
+    IResult, Parser,
+};
+
+pub type Vector = [i128; 3];
+
+pub fn add(a: Vector, b: Vector) -> Vector {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+pub fn sub(a: Vector, b: Vector) -> Vector {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+pub fn scale(a: Vector, k: i128) -> Vector {
+    a.map(|x| x * k)
+}
+
+pub fn dot(a: Vector, b: Vector) -> i128 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+pub fn cross(a: Vector, b: Vector) -> Vector {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+/// Object moving in a straight line at constant velocity, one step per nanosecond
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hailstone {
+    pub position: Vector,
+    pub velocity: Vector,
+}
+
+impl Hailstone {
+    pub fn at(&self, time: i128) -> Vector {
+        add(self.position, scale(self.velocity, time))
+    }
+
+    /// Whether both objects are at the same position at the same whole nanosecond, now or later
+    pub fn collides(&self, other: &Hailstone) -> bool {
+        // `p1 + t v1 = p2 + t v2` means `p2 - p1 = t (v1 - v2)`
+        let position = sub(other.position, self.position);
+        let velocity = sub(self.velocity, other.velocity);
+        match velocity.iter().position(|&v| v != 0) {
+            // Objects moving together only meet if they already are at the same position
+            None => position == [0; 3],
+            Some(axis) => {
+                let time = position[axis] / velocity[axis];
+                position[axis] % velocity[axis] == 0
+                    && time >= 0
+                    && position == scale(velocity, time)
+            }
+        }
+    }
+}
+
+fn parse_vector(input: &str) -> IResult<&str, Vector> {
+    tuple((
+        complete::i64,
+        preceded(tag(",").and(space1), complete::i64),
+        preceded(tag(",").and(space1), complete::i64),
+    ))
+    .map(|(x, y, z)| [x, y, z].map(i128::from))
+    .parse(input)
+}
+
+// `19, 13, 30 @ -2,  1, -2`
+pub fn parse_hailstones(input: &str) -> IResult<&str, Vec<Hailstone>> {
+    lines(
+        separated_pair(
+            parse_vector,
+            delimited(space0, tag("@"), space1),
+            parse_vector,
+        )
+        .map(|(position, velocity)| Hailstone { position, velocity }),
+    )(input)
+}
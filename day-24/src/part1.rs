@@ -0,0 +1,89 @@
+use crate::hail::{parse_hailstones, Hailstone};
+use aoc_common::{custom_error::AocError, parsing::parse_complete};
+use itertools::Itertools;
+use std::ops::RangeInclusive;
+
+const TEST_AREA: RangeInclusive<i128> = 200000000000000..=400000000000000;
+
+// Whether the paths of two hailstones cross inside the test area in the future, ignoring the Z axis
+fn cross_inside(a: &Hailstone, b: &Hailstone, area: &RangeInclusive<i128>) -> bool {
+    let ([ax, ay, _], [avx, avy, _]) = (a.position, a.velocity);
+    let ([bx, by, _], [bvx, bvy, _]) = (b.position, b.velocity);
+
+    // Solve `a + t av = b + s bv` with Cramer's rule, both times being `_num / det`
+    let det = avx * bvy - avy * bvx;
+    if det == 0 {
+        // Parallel paths
+        return false;
+    }
+    let (dx, dy) = (bx - ax, by - ay);
+    let t_num = dx * bvy - dy * bvx;
+    let s_num = dx * avy - dy * avx;
+    // Normalize the sign of the denominator to compare fractions without dividing
+    let (det, t_num, s_num) = if det < 0 {
+        (-det, -t_num, -s_num)
+    } else {
+        (det, t_num, s_num)
+    };
+    if t_num < 0 || s_num < 0 {
+        return false;
+    }
+
+    let x = ax * det + avx * t_num;
+    let y = ay * det + avy * t_num;
+    let scaled = area.start() * det..=area.end() * det;
+    scaled.contains(&x) && scaled.contains(&y)
+}
+
+pub fn process_area(input: &str, area: RangeInclusive<i128>) -> miette::Result<usize, AocError> {
+    let hailstones = parse_complete(input, parse_hailstones)?;
+
+    Ok(hailstones
+        .iter()
+        .tuple_combinations()
+        .filter(|(a, b)| cross_inside(a, b, &area))
+        .count())
+}
+
+#[tracing::instrument]
+pub fn process(input: &str) -> miette::Result<usize, AocError> {
+    process_area(input, TEST_AREA)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const INPUT: &str = "19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3";
+
+    #[rstest]
+    #[case(0, 1, true)]
+    #[case(0, 2, true)]
+    #[case(0, 3, false)] // Outside the test area
+    #[case(0, 4, false)] // In the past of hailstone 0
+    #[case(1, 2, false)] // Parallel
+    #[case(3, 4, false)] // In the past of both
+    fn test_pairs(
+        #[case] a: usize,
+        #[case] b: usize,
+        #[case] expected: bool,
+    ) -> miette::Result<()> {
+        let hailstones = parse_complete(INPUT, parse_hailstones)?;
+        assert_eq!(
+            expected,
+            cross_inside(&hailstones[a], &hailstones[b], &(7..=27))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        assert_eq!(2, process_area(INPUT, 7..=27)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,136 @@
+use crate::hail::{cross, dot, parse_hailstones, scale, sub, Hailstone};
+use aoc_common::{custom_error::AocError, parsing::parse_complete};
+use itertools::Itertools;
+
+// Time at which a hailstone crosses the plane through the origin with the given normal, if a whole number
+fn plane_crossing(hailstone: &Hailstone, normal: [i128; 3]) -> Option<i128> {
+    let speed = dot(hailstone.velocity, normal);
+    let distance = -dot(hailstone.position, normal);
+    (speed != 0 && distance % speed == 0).then(|| distance / speed)
+}
+
+// Rock thrown to hit the four hailstones, from the frame of reference of the first one
+//
+// There the first hailstone stands still at the origin, so the rock's path goes through the origin.
+// It also meets the second hailstone's path, so it lies in the plane they both span. The times at
+// which the other two hailstones cross that plane are when the rock hits them, which pins it down.
+fn throw(reference: &Hailstone, others: [&Hailstone; 3]) -> Option<Hailstone> {
+    let [a, b, c] = others.map(|h| Hailstone {
+        position: sub(h.position, reference.position),
+        velocity: sub(h.velocity, reference.velocity),
+    });
+    let normal = cross(a.position, a.velocity);
+    if normal == [0; 3] {
+        return None;
+    }
+
+    let (t1, t2) = (plane_crossing(&b, normal)?, plane_crossing(&c, normal)?);
+    if t1 == t2 {
+        return None;
+    }
+    // Hit positions are the same in every frame, as the time does not depend on it
+    let (hit1, hit2) = (others[1].at(t1), others[2].at(t2));
+    let displacement = sub(hit2, hit1);
+    let elapsed = t2 - t1;
+    if displacement.iter().any(|d| d % elapsed != 0) {
+        return None;
+    }
+    let velocity = displacement.map(|d| d / elapsed);
+    let position = sub(hit1, scale(velocity, t1));
+
+    Some(Hailstone { position, velocity })
+}
+
+#[tracing::instrument]
+pub fn process(input: &str) -> miette::Result<i128, AocError> {
+    let hailstones = parse_complete(input, parse_hailstones)?;
+
+    // Any four hailstones in general position determine the rock, which must then hit all of them
+    let rock = hailstones
+        .iter()
+        .tuple_combinations()
+        .filter_map(|(reference, a, b, c)| throw(reference, [a, b, c]))
+        .find(|rock| hailstones.iter().all(|h| h.collides(rock)))
+        .ok_or_else(|| AocError::NoSolution("no rock hits every hailstone".to_owned()))?;
+
+    Ok(rock.position.iter().sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hail::Vector;
+    use rstest::rstest;
+
+    const INPUT: &str = "19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3";
+
+    #[test]
+    fn test_throw() -> miette::Result<()> {
+        let hailstones = parse_complete(INPUT, parse_hailstones)?;
+        let rock = throw(
+            &hailstones[0],
+            [&hailstones[1], &hailstones[2], &hailstones[3]],
+        );
+        assert_eq!(
+            Some(Hailstone {
+                position: [24, 13, 10],
+                velocity: [-3, 1, 2]
+            }),
+            rock
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    // Same object
+    #[case([0, 0, 0], [1, 0, 0], [0, 0, 0], [1, 0, 0], true)]
+    // Parallel paths, offset from one another
+    #[case([0, 0, 0], [1, 0, 0], [0, 1, 0], [1, 0, 0], false)]
+    #[case([0, 0, 0], [1, 0, 0], [3, 0, 0], [1, 0, 0], false)]
+    // Collision 2 nanoseconds from now
+    #[case([0, 0, 0], [0, 0, 0], [4, 2, 0], [-2, -1, 0], true)]
+    // Collision 2 nanoseconds ago
+    #[case([0, 0, 0], [0, 0, 0], [4, 2, 0], [2, 1, 0], false)]
+    // Collision half way through a nanosecond
+    #[case([0, 0, 0], [0, 0, 0], [1, 0, 0], [-2, 0, 0], false)]
+    // Crossing paths at different times
+    #[case([0, 0, 0], [0, 0, 0], [4, 2, 0], [-2, -2, 0], false)]
+    fn test_collides(
+        #[case] position_a: Vector,
+        #[case] velocity_a: Vector,
+        #[case] position_b: Vector,
+        #[case] velocity_b: Vector,
+        #[case] expected: bool,
+    ) {
+        let a = Hailstone {
+            position: position_a,
+            velocity: velocity_a,
+        };
+        let b = Hailstone {
+            position: position_b,
+            velocity: velocity_b,
+        };
+        assert_eq!(expected, a.collides(&b));
+        assert_eq!(expected, b.collides(&a));
+    }
+
+    #[test]
+    fn test_no_rock() {
+        // Hailstones on parallel paths in different directions
+        let input = "0, 0, 0 @ 1, 0, 0
+0, 1, 0 @ 0, 0, 1
+0, 2, 0 @ 1, 0, 0
+3, 3, 5 @ 0, 0, 1";
+        assert!(matches!(process(input), Err(AocError::NoSolution(_))));
+    }
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        assert_eq!(47, process(INPUT)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use crate::wiring::parse_wiring;
+use aoc_common::{custom_error::AocError, parsing::parse_complete};
+
+const CUT_WIRES: usize = 3;
+
+#[tracing::instrument]
+pub fn process(input: &str) -> miette::Result<usize, AocError> {
+    let wiring = parse_complete(input, parse_wiring)?;
+
+    let cut = wiring.cut(CUT_WIRES).ok_or_else(|| {
+        AocError::NoSolution(format!("no {CUT_WIRES} wires split the components"))
+    })?;
+    Ok(cut.group_sizes.iter().product())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr";
+
+    #[test]
+    fn test_cut() -> miette::Result<()> {
+        let wiring = parse_complete(INPUT, parse_wiring)?;
+        assert_eq!(15, wiring.graph.node_count());
+        assert_eq!(33, wiring.graph.edge_count());
+
+        let cut = wiring.cut(CUT_WIRES).expect("the example has a cut");
+        assert_eq!(
+            vec![("bvb", "cmg"), ("hfx", "pzl"), ("jqt", "nvd")],
+            cut.wires
+        );
+        let mut sizes = cut.group_sizes;
+        sizes.sort_unstable();
+        assert_eq!([6, 9], sizes);
+        // Two wires are not enough
+        assert_eq!(None, wiring.cut(2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        assert_eq!(54, process(INPUT)?);
+        Ok(())
+    }
+}
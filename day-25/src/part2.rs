@@ -0,0 +1,7 @@
+use aoc_common::custom_error::AocError;
+
+/// The last day has no second puzzle, only a button to push once every other star is collected
+#[tracing::instrument]
+pub fn process(_input: &str) -> miette::Result<&'static str, AocError> {
+    Ok("Merry Christmas!")
+}
@@ -0,0 +1,144 @@
+use aoc_common::parsing::lines;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{alpha1, space1},
+    multi::separated_list1,
+    sequence::separated_pair,
+    IResult, Parser,
+};
+use petgraph::{
+    graph::{EdgeIndex, NodeIndex, UnGraph},
+    visit::EdgeRef,
+};
+use std::collections::{HashMap, VecDeque};
+
+/// Components connected by wires
+#[derive(Debug)]
+pub struct Wiring<'a> {
+    pub graph: UnGraph<&'a str, ()>,
+}
+
+/// Wires splitting the components into two disconnected groups once removed
+#[derive(Debug, PartialEq, Eq)]
+pub struct Cut<'a> {
+    /// Components at both ends of each wire, in alphabetical order
+    pub wires: Vec<(&'a str, &'a str)>,
+    pub group_sizes: [usize; 2],
+}
+
+impl<'a> Wiring<'a> {
+    fn new(connections: Vec<(&'a str, Vec<&'a str>)>) -> Self {
+        let mut graph = UnGraph::new_undirected();
+        let mut indices = HashMap::new();
+        let mut index = |graph: &mut UnGraph<&'a str, ()>, name| {
+            *indices.entry(name).or_insert_with(|| graph.add_node(name))
+        };
+        for (component, others) in connections {
+            let a = index(&mut graph, component);
+            for other in others {
+                let b = index(&mut graph, other);
+                graph.add_edge(a, b, ());
+            }
+        }
+        Self { graph }
+    }
+
+    /// Cut of exactly `size` wires, if the wiring has one
+    ///
+    /// Every wire carries one unit of flow. Some component lies on the other side of the cut from
+    /// the first one, and the maximum flow between them equals the size of the cut (max-flow min-cut).
+    pub fn cut(&self, size: usize) -> Option<Cut<'a>> {
+        let source = self.graph.node_indices().next()?;
+        self.graph
+            .node_indices()
+            .skip(1)
+            .find_map(|sink| self.cut_between(source, sink, size))
+    }
+
+    // Minimum cut between two components, if made of `size` wires (Edmonds–Karp)
+    fn cut_between(&self, source: NodeIndex, sink: NodeIndex, size: usize) -> Option<Cut<'a>> {
+        // Flow through each wire, positive when going from its first to its second endpoint
+        let mut flow = vec![0i8; self.graph.edge_count()];
+        let mut paths = 0;
+        loop {
+            let previous = self.augmenting_paths(source, &flow);
+            if previous[sink.index()].is_none() {
+                break;
+            }
+            paths += 1;
+            if paths > size {
+                return None;
+            }
+            let mut node = sink;
+            while node != source {
+                let (edge, from) = previous[node.index()]?;
+                let (a, _) = self.graph.edge_endpoints(edge)?;
+                flow[edge.index()] += if from == a { 1 } else { -1 };
+                node = from;
+            }
+        }
+        if paths < size {
+            return None;
+        }
+
+        // Components still reachable from the source form one side of the cut
+        let reachable = self.augmenting_paths(source, &flow);
+        let side = |node: NodeIndex| reachable[node.index()].is_some() || node == source;
+        let mut wires: Vec<_> = self
+            .graph
+            .edge_references()
+            .filter(|edge| side(edge.source()) != side(edge.target()))
+            .map(|edge| {
+                let (a, b) = (self.graph[edge.source()], self.graph[edge.target()]);
+                (a.min(b), a.max(b))
+            })
+            .collect();
+        wires.sort_unstable();
+        let group = self.graph.node_indices().filter(|&node| side(node)).count();
+
+        Some(Cut {
+            wires,
+            group_sizes: [group, self.graph.node_count() - group],
+        })
+    }
+
+    // Breadth-first search through wires with spare capacity, giving for each reached component
+    // the wire it was reached through and the component on its other end
+    fn augmenting_paths(
+        &self,
+        source: NodeIndex,
+        flow: &[i8],
+    ) -> Vec<Option<(EdgeIndex, NodeIndex)>> {
+        let mut previous = vec![None; self.graph.node_count()];
+        let mut queue = VecDeque::from([source]);
+        while let Some(node) = queue.pop_front() {
+            for edge in self.graph.edges(node) {
+                let next = edge.target();
+                let forward = match self.graph.edge_endpoints(edge.id()) {
+                    Some((a, _)) if a == node => 1,
+                    _ => -1,
+                };
+                if next == source
+                    || previous[next.index()].is_some()
+                    || flow[edge.id().index()] * forward >= 1
+                {
+                    continue;
+                }
+                previous[next.index()] = Some((edge.id(), node));
+                queue.push_back(next);
+            }
+        }
+        previous
+    }
+}
+
+// `jqt: rhn xhk nvd`
+pub fn parse_wiring(input: &str) -> IResult<&str, Wiring<'_>> {
+    lines(separated_pair(
+        alpha1,
+        tag(": "),
+        separated_list1(space1, alpha1),
+    ))
+    .map(Wiring::new)
+    .parse(input)
+}
@@ -0,0 +1,35 @@
+pub use aoc_common::solution::{Answer, DynSolution, Part, Solution};
+
+/// Solutions of every day available in the workspace, ordered by day
+pub static SOLUTIONS: &[DynSolution] = &[
+    DynSolution::of::<day_01::Day01>(),
+    DynSolution::of::<day_02::Day02>(),
+    DynSolution::of::<day_03::Day03>(),
+    DynSolution::of::<day_04::Day04>(),
+    DynSolution::of::<day_05::Day05>(),
+    DynSolution::of::<day_06::Day06>(),
+    DynSolution::of::<day_07::Day07>(),
+    DynSolution::of::<day_08::Day08>(),
+    DynSolution::of::<day_09::Day09>(),
+    DynSolution::of::<day_10::Day10>(),
+    DynSolution::of::<day_11::Day11>(),
+    DynSolution::of::<day_12::Day12>(),
+    DynSolution::of::<day_13::Day13>(),
+    DynSolution::of::<day_14::Day14>(),
+    DynSolution::of::<day_15::Day15>(),
+    DynSolution::of::<day_16::Day16>(),
+    DynSolution::of::<day_17::Day17>(),
+    DynSolution::of::<day_18::Day18>(),
+    DynSolution::of::<day_19::Day19>(),
+    DynSolution::of::<day_20::Day20>(),
+    DynSolution::of::<day_21::Day21>(),
+    DynSolution::of::<day_22::Day22>(),
+    DynSolution::of::<day_23::Day23>(),
+    DynSolution::of::<day_24::Day24>(),
+    DynSolution::of::<day_25::Day25>(),
+];
+
+/// Find the solution for a given day
+pub fn solution(day: u8) -> Option<&'static DynSolution> {
+    SOLUTIONS.iter().find(|solution| solution.day == day)
+}
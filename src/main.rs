@@ -1,3 +1,78 @@
-fn main() {
-    println!("Hello, world!");
+use aoc::{Part, SOLUTIONS};
+use clap::{Parser, Subcommand};
+use miette::{Context, IntoDiagnostic};
+use std::io::{self, Read};
+use std::time::Instant;
+
+/// Advent of Code 2023 solutions runner
+#[derive(Debug, Parser)]
+#[command(name = "aoc", version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Run the solution of a given day and part against some input
+    Run {
+        /// Day of the puzzle
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Part of the puzzle
+        #[arg(short, long)]
+        part: Part,
+        /// Path to the puzzle input, use `-` to read it from stdin
+        #[arg(short, long, default_value = "-")]
+        input: String,
+    },
+    /// List the days with available solutions
+    List,
+}
+
+fn read_input(path: &str) -> miette::Result<String> {
+    match path {
+        "-" => {
+            let mut buffer = String::new();
+            io::stdin()
+                .read_to_string(&mut buffer)
+                .into_diagnostic()
+                .wrap_err("reading input from stdin")?;
+            Ok(buffer)
+        }
+        _ => std::fs::read_to_string(path)
+            .into_diagnostic()
+            .wrap_err_with(|| format!("reading input file `{path}`")),
+    }
+}
+
+#[tracing::instrument]
+fn main() -> miette::Result<()> {
+    tracing_subscriber::fmt::init();
+
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run { day, part, input } => {
+            let solution = aoc::solution(day)
+                .ok_or_else(|| miette::miette!("Day {day:02} is not available"))?;
+            let input = read_input(&input)?;
+
+            let start = Instant::now();
+            let answer = solution
+                .solve(part, &input)
+                .wrap_err_with(|| format!("day {day:02} part {part}"))?;
+            let elapsed = start.elapsed();
+
+            println!("{}", answer);
+            eprintln!("Day {day:02} part {part} solved in {elapsed:?}");
+        }
+        Command::List => {
+            for solution in SOLUTIONS {
+                println!("Day {:02}", solution.day);
+            }
+        }
+    }
+
+    Ok(())
 }
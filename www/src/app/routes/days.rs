@@ -1,5 +1,8 @@
+use aoc::{DynSolution, Part};
 use leptos::*;
 use leptos_router::*;
+use miette::NarratableReportHandler;
+use std::time::Duration;
 use tracing::*;
 
 #[derive(Params, PartialEq, Clone)]
@@ -12,7 +15,6 @@ struct Day {
 #[component]
 pub fn SolutionPage() -> impl IntoView {
     let params = use_params::<Day>();
-    let solve = create_server_action::<Solve>();
 
     let Ok(day) = params.get() else {
         return view! {
@@ -26,14 +28,69 @@ pub fn SolutionPage() -> impl IntoView {
                 class="grid grid-cols-3 gap-4 mx-auto max-w-7xl sm:px-6 lg:px-8 py-14 bg-ctp-base border-2 rounded-xl"
                 style="border-color: hsla(105, 69%, 30%, 50%)"
             >
-            <ActionForm class="text-ctp-text relative" action={solve}>
-                <input name="day" value={day.day} type="hidden" />
-                <input name="part" value={day.part} type="hidden" />
+                <SolveForm day={day.day} part={day.part}/>
+            </div>
+        </div>
+    }
+}
 
+/// Input form along with the answer once solved
+///
+/// Solutions that can run in WebAssembly are solved right in the browser, so private inputs never
+/// leave it. The others (e.g. relying on threads) fall back to the `solve` server function.
+#[island]
+pub fn SolveForm(day: String, part: String) -> impl IntoView {
+    let solve = create_server_action::<Solve>();
+    let pending = solve.pending();
+    let textarea = create_node_ref::<html::Textarea>();
+    // Answer or error message of the last solve
+    let result = create_rw_signal(None::<Result<String, String>>);
+    // Whether a solve is running in the browser, `pending` only tracks the server action
+    let solving = create_rw_signal(false);
+    let in_browser = lookup(&day, &part).is_ok_and(|(solution, _)| solution.wasm_compatible);
 
+    create_effect(move |_| {
+        if let Some(answer) = solve.value().get() {
+            result.set(Some(answer.map_err(error_message)));
+        }
+    });
+
+    let on_submit = move |ev: ev::SubmitEvent| {
+        ev.prevent_default();
+        let input = textarea.get().expect("textarea is mounted").value();
+        if in_browser {
+            solving.set(true);
+            let (day, part) = (day.clone(), part.clone());
+            // Solving blocks the main thread, so let the browser paint "Solving..." first
+            request_animation_frame(move || {
+                set_timeout(
+                    move || {
+                        let answer = lookup(&day, &part).and_then(|(solution, part)| {
+                            solution
+                                .solve(part, &input)
+                                .map(|answer| answer.to_string())
+                                .map_err(render_report)
+                        });
+                        result.set(Some(answer));
+                        solving.set(false);
+                    },
+                    Duration::ZERO,
+                )
+            });
+        } else {
+            solve.dispatch(Solve {
+                day: day.clone(),
+                part: part.clone(),
+                input,
+            });
+        }
+    };
+
+    view! {
+            <form class="text-ctp-text relative col-span-2" on:submit=on_submit>
                 <div class="overflow-hidden rounded-lg shadow-sm ring-1 ring-inset ring-gray-300 focus-within:ring-2 focus-within:ring-teal-600">
                     <label for="input" class="sr-only">Add your input</label>
-                    <textarea rows="3" name="input" id="input" class="block w-full resize-none border-0 bg-transparent py-1.5 text-ctp-text placeholder:text-gray-400 focus:ring-0 sm:text-sm sm:leading-6" placeholder="Add your input..."></textarea>
+                    <textarea node_ref=textarea rows="3" name="input" id="input" class="block w-full resize-none border-0 bg-transparent py-1.5 text-ctp-text placeholder:text-gray-400 focus:ring-0 sm:text-sm sm:leading-6" placeholder="Add your input..."></textarea>
 
                     // <!-- Spacer element to match the height of the toolbar -->
                     <div class="py-2" aria-hidden="true">
@@ -44,15 +101,58 @@ pub fn SolutionPage() -> impl IntoView {
                     </div>
                     </div>
                     <div class="absolute inset-x-0 bottom-0 flex justify-between py-2 pl-3 pr-2">
-                    <div/>
+                    <p class="self-center text-xs text-ctp-subtext0">
+                        {if in_browser {
+                            "Solved in your browser, your input is not sent anywhere"
+                        } else {
+                            "Solved on the server"
+                        }}
+                    </p>
                     <div class="flex-shrink-0">
-                        <button type="submit" class="inline-flex items-center rounded-md bg-teal-600 px-3 py-2 text-sm font-semibold text-white shadow-sm hover:bg-teal-500 focus-visible:outline focus-visible:outline-2 focus-visible:outline-offset-2 focus-visible:outline-teal-600">Solve</button>
+                        <button type="submit" disabled=move || pending.get() || solving.get() class="inline-flex items-center rounded-md bg-teal-600 px-3 py-2 text-sm font-semibold text-white shadow-sm hover:bg-teal-500 focus-visible:outline focus-visible:outline-2 focus-visible:outline-offset-2 focus-visible:outline-teal-600 disabled:opacity-50">Solve</button>
                     </div>
                 </div>
 
-            </ActionForm>
+            </form>
+            <div class="text-ctp-text">
+                {move || match (pending.get() || solving.get(), result.get()) {
+                    (true, _) => view! { <p class="text-ctp-subtext0">"Solving..."</p> }.into_view(),
+                    (false, None) => ().into_view(),
+                    (false, Some(Ok(answer))) => view! {
+                        <p class="text-sm text-ctp-subtext0">"Answer"</p>
+                        <p class="text-2xl font-bold text-ctp-green">{answer}</p>
+                    }.into_view(),
+                    (false, Some(Err(message))) => view! {
+                        <p class="text-sm text-ctp-subtext0">"Error"</p>
+                        <pre class="text-sm whitespace-pre-wrap text-ctp-red">{message}</pre>
+                    }.into_view(),
+                }}
             </div>
-        </div>
+    }
+}
+
+/// Solution and part of the puzzle from the route parameters
+fn lookup(day: &str, part: &str) -> Result<(&'static DynSolution, Part), String> {
+    let day: u8 = day.parse().map_err(|_| format!("invalid day `{day}`"))?;
+    let part: Part = part.parse().map_err(|error| format!("{error}"))?;
+    let solution = aoc::solution(day).ok_or_else(|| format!("day {day:02} is not available"))?;
+    Ok((solution, part))
+}
+
+// Plain text rendering of a diagnostic, including the location of parse errors
+fn render_report(report: miette::Report) -> String {
+    let mut message = String::new();
+    match NarratableReportHandler::new().render_report(&mut message, report.as_ref()) {
+        Ok(()) => message,
+        Err(_) => report.to_string(),
+    }
+}
+
+// Message of a failed solve, without the generic prefix of server function errors
+fn error_message(error: ServerFnError) -> String {
+    match error {
+        ServerFnError::Args(message) | ServerFnError::ServerError(message) => message,
+        other => other.to_string(),
     }
 }
 
@@ -81,14 +181,22 @@ pub fn Hero() -> impl IntoView {
     };
 }
 
-#[tracing::instrument]
+#[tracing::instrument(skip(input))]
 #[server]
 async fn solve(
     day: String,
     part: String,
     input: String,
-) -> Result<Vec<()>, ServerFnError> {
-    info!(day, part, input);
-    let posts = vec![];
-    Ok(posts)
+) -> Result<String, ServerFnError> {
+    info!(day, part);
+    let (solution, part) = lookup(&day, &part).map_err(ServerFnError::Args)?;
+
+    // Solutions are CPU bound, keep them off the async runtime
+    let answer = tokio::task::spawn_blocking(move || solution.solve(part, &input))
+        .await
+        .map_err(|error| ServerFnError::ServerError(error.to_string()))?;
+
+    answer
+        .map(|answer| answer.to_string())
+        .map_err(|report| ServerFnError::ServerError(render_report(report)))
 }